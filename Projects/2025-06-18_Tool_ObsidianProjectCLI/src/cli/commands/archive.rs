@@ -0,0 +1,181 @@
+use anyhow::{Context, Result};
+use chrono::Datelike;
+use colored::*;
+use std::path::{Path, PathBuf};
+use crate::cli::commands::status::apply_status_change;
+use crate::cli::ProjectStatus;
+use crate::config::Settings;
+use crate::core::{self, Project};
+use crate::fs::operations::update_vault_links;
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::error;
+use crate::utils::export::project_object;
+use crate::utils::markdown::vault_relative;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn archive(project_name: String, note: Option<String>, dry_run: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    let archive_path = settings.archive_path();
+    let (mut project, from) = find_project(&settings, &project_name).await?;
+
+    if core::is_in_archive(&archive_path, &from) {
+        return Err(error::conflict(format!(
+            "'{}' is already archived (use 'opj unarchive' to restore it)",
+            project.name
+        )));
+    }
+    let to = core::archive_destination(&archive_path, &from, chrono::Local::now().year())
+        .with_context(|| format!("Cannot archive {}", from.display()))?;
+
+    let old_status = project.status.clone();
+    check_destination(&to)?;
+    let original = project.clone();
+    if !dry_run {
+        // Remembered so that unarchive puts nested folders back where they were
+        project.archived_from = Some(vault_relative(&from, &settings.obsidian_path));
+        set_status(&mut project, core::ProjectStatus::Archived, note).await?;
+    }
+    let links = move_project(&settings, &mut project, original, &from, &to, dry_run).await?;
+
+    report("📦", "Archived", &settings, &project, &old_status, &core::ProjectStatus::Archived, &to, &links, dry_run)
+}
+
+pub async fn unarchive(project_name: String, status: Option<ProjectStatus>, dry_run: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    let (mut project, from) = find_project(&settings, &project_name).await?;
+
+    if !core::is_in_archive(&settings.archive_path(), &from) {
+        return Err(error::conflict(format!("'{}' is not in the archive", project.name)));
+    }
+    let to = core::restore_destination(
+        &settings.obsidian_path,
+        &settings.projects_path(),
+        &from,
+        project.archived_from.as_deref(),
+    ).with_context(|| format!("Cannot restore {}", from.display()))?;
+
+    let old_status = project.status.clone();
+    let new_status = status.map(Into::into).unwrap_or_else(|| core::status_before_archive(&project));
+    check_destination(&to)?;
+    let original = project.clone();
+    if !dry_run {
+        project.archived_from = None;
+        set_status(&mut project, new_status.clone(), Some("Restored from archive".to_string())).await?;
+    }
+    let links = move_project(&settings, &mut project, original, &from, &to, dry_run).await?;
+
+    report("📂", "Restored", &settings, &project, &old_status, &new_status, &to, &links, dry_run)
+}
+
+/// Change the status, or only save the other changes when it already matches.
+async fn set_status(project: &mut Project, status: core::ProjectStatus, note: Option<String>) -> Result<()> {
+    if project.status != status {
+        return apply_status_change(project, status, note).await;
+    }
+    match project.path.clone() {
+        Some(dir) => core::save_project_metadata(project, &dir).await,
+        None => Ok(()),
+    }
+}
+
+/// Look the project up among active and archived projects alike.
+async fn find_project(settings: &Settings, name: &str) -> Result<(Project, PathBuf)> {
+    let projects = ProjectScanner::from_settings(settings)?
+        .include_archived(true)
+        .scan_projects()
+        .await?;
+    let project = scanner::find_by_name(&projects, name)
+        .cloned()
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", name)))?;
+    let dir = project.path.clone()
+        .ok_or_else(|| error::not_found(format!("Project '{}' has no directory", name)))?;
+    Ok((project, dir))
+}
+
+/// Move the project folder, whose new status is already saved, and repoint
+/// links to it. If the folder cannot be moved, the metadata is put back to
+/// `original`. Returns the notes whose links were (or, on a dry run, would
+/// be) updated.
+async fn move_project(
+    settings: &Settings,
+    project: &mut Project,
+    original: Project,
+    from: &Path,
+    to: &Path,
+    dry_run: bool,
+) -> Result<Vec<PathBuf>> {
+    if !dry_run {
+        if let Err(err) = move_folder(from, to).await {
+            let mut original = original;
+            original.metadata_state = project.metadata_state.clone();
+            if let Err(restore_err) = core::save_project_metadata(&mut original, from).await {
+                eprintln!("Warning: could not restore the status of '{}': {:#}", original.name, restore_err);
+            }
+            return Err(err);
+        }
+        project.path = Some(to.to_path_buf());
+    }
+
+    update_vault_links(&settings.obsidian_path, from, to, dry_run).await
+}
+
+fn check_destination(to: &Path) -> Result<()> {
+    if to.exists() {
+        return Err(error::conflict(format!("{} already exists", to.display())));
+    }
+    Ok(())
+}
+
+async fn move_folder(from: &Path, to: &Path) -> Result<()> {
+    check_destination(to)?;
+    if let Some(parent) = to.parent() {
+        tokio::fs::create_dir_all(parent).await
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    tokio::fs::rename(from, to).await
+        .with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))?;
+    crate::fs::history::record_move(from, to)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn report(
+    icon: &str,
+    verb: &str,
+    settings: &Settings,
+    project: &Project,
+    from: &core::ProjectStatus,
+    to: &core::ProjectStatus,
+    destination: &Path,
+    links: &[PathBuf],
+    dry_run: bool,
+) -> Result<()> {
+    let vault = &settings.obsidian_path;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_object(project),
+            "dry_run": dry_run,
+            "from": from.to_string(),
+            "to": to.to_string(),
+            "path": destination.display().to_string(),
+            "links_updated": links.iter().map(|note| vault_relative(note, vault)).collect::<Vec<_>>(),
+        }));
+    }
+
+    println!("{} {} \"{}\"", icon.bright_blue(), verb, project.name.bright_white().bold());
+    println!("   Status: {} → {}", from.to_string().bright_black(), to.to_string().bright_green());
+    println!("   Folder: {}", vault_relative(destination, vault).bright_cyan());
+    if !links.is_empty() {
+        println!("   Links updated in {} note(s):", links.len());
+        for note in links {
+            println!("     • {}", vault_relative(note, vault).bright_black());
+        }
+    }
+
+    if dry_run {
+        println!();
+        println!("{} Dry run: nothing was moved or changed", "ℹ️".bright_blue());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anyhow::Result;
+use colored::*;
+use std::collections::BTreeMap;
+use crate::cli::AttentionFilter;
+use crate::config::Settings;
+use crate::core::{self, AttentionItem, AttentionKind};
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::export::project_ref;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(kinds: Vec<AttentionFilter>, query: Option<String>) -> Result<()> {
+    let settings = Settings::load().await?;
+    let query = settings.parse_query(query.as_deref())?;
+    let scanner = ProjectScanner::from_settings(&settings)?.include_archived(true);
+    let projects = scanner.scan_projects().await?;
+
+    let note_statuses: BTreeMap<String, core::ProjectStatus> = projects.iter()
+        .filter_map(|p| Some((p.id.clone(), scanner::overview_status(p.path.as_ref()?)?)))
+        .collect();
+
+    // Check against every project, archived ones included, so relations to
+    // filtered-out ones still resolve
+    let kinds: Vec<AttentionKind> = kinds.into_iter().map(Into::into).collect();
+    let items: Vec<AttentionItem> = core::find_attention(&projects, &settings.attention, &note_statuses, chrono::Utc::now())
+        .into_iter()
+        .filter(|item| !scanner.is_archived(item.project) && query.matches(item.project))
+        .filter_map(|mut item| {
+            if !kinds.is_empty() {
+                item.reasons.retain(|reason| kinds.contains(&reason.kind));
+            }
+            (!item.reasons.is_empty()).then_some(item)
+        })
+        .collect();
+
+    if json_output() {
+        let projects: Vec<serde_json::Value> = items.iter()
+            .map(|item| serde_json::json!({
+                "project": project_ref(item.project),
+                "reasons": item.reasons.iter()
+                    .map(|reason| serde_json::json!({ "kind": reason.kind, "message": reason.message }))
+                    .collect::<Vec<_>>(),
+            }))
+            .collect();
+        return emit_json(serde_json::json!({ "projects": projects }));
+    }
+
+    if items.is_empty() {
+        println!("{} Nothing needs attention", "✅".bright_green());
+        return Ok(());
+    }
+
+    println!("{} {} project(s) need attention", "🔎".bright_cyan(), items.len());
+    println!();
+
+    for item in &items {
+        println!("{} ({})", item.project.name.bright_white().bold(), item.project.status.to_string().bright_black());
+        for reason in &item.reasons {
+            println!("  {} {}", kind_icon(reason.kind), reason.message);
+        }
+        println!();
+    }
+
+    println!("{}", "💡 Thresholds per status are set under 'attention' in the config file".bright_black());
+
+    Ok(())
+}
+
+fn kind_icon(kind: AttentionKind) -> ColoredString {
+    match kind {
+        AttentionKind::Overdue => "⏰ overdue".bright_red(),
+        AttentionKind::Stale => "💤 stale".bright_yellow(),
+        AttentionKind::LongInStatus => "🐢 stuck".bright_magenta(),
+        AttentionKind::Inconsistent => "⚠️ inconsistent".bright_yellow(),
+    }
+}
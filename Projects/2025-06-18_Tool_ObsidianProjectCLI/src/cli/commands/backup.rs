@@ -0,0 +1,216 @@
+use anyhow::Result;
+use colored::*;
+use std::path::{Path, PathBuf};
+use crate::cli::ConflictPolicy;
+use crate::config::Settings;
+use crate::fs::backup::{self, BackupEntry, OnConflict, RestoreAction, RestoreItem, BACKUP_DIR, BACKUP_EXTENSION};
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::error;
+use crate::utils::output::{emit_json, format_bytes, json_output};
+
+pub async fn backup(project: Option<String>, out: Option<String>) -> Result<()> {
+    let settings = Settings::load().await?;
+    let vault_path = &settings.obsidian_path;
+    let mut projects = ProjectScanner::from_settings(&settings)?
+        .include_archived(true)
+        .scan_projects()
+        .await?;
+
+    if let Some(name) = &project {
+        let found = scanner::find_by_name(&projects, name)
+            .cloned()
+            .ok_or_else(|| error::not_found(format!("Project '{}' not found", name)))?;
+        projects = vec![found];
+    }
+    // Backups restore into the same place, so only folders inside the vault count
+    projects.retain(|p| p.path.as_ref().is_some_and(|path| path.starts_with(vault_path)));
+    projects.sort_by(|a, b| a.path.cmp(&b.path));
+    if projects.is_empty() {
+        return Err(error::not_found("No projects to back up"));
+    }
+
+    let out = out.map(PathBuf::from).unwrap_or_else(|| {
+        let name = format!("opj-{}.{}", chrono::Local::now().format("%Y%m%d-%H%M%S"), BACKUP_EXTENSION);
+        vault_path.join(BACKUP_DIR).join(name)
+    });
+    if out.exists() {
+        return Err(error::conflict(format!("{} already exists", out.display())));
+    }
+
+    let manifest = backup::create_backup(&out, vault_path, &projects)?;
+    let bytes = std::fs::metadata(&out).map(|m| m.len()).unwrap_or(0);
+    let files: usize = manifest.projects.iter().map(|entry| entry.files).sum();
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "path": out.display().to_string(),
+            "created": manifest.created,
+            "projects": manifest.projects.iter().map(entry_object).collect::<Vec<_>>(),
+            "files": files,
+            "bytes": bytes,
+        }));
+    }
+
+    println!("{} Backed up {} project(s), {} files ({})",
+        "💾".bright_blue(),
+        manifest.projects.len(),
+        files,
+        format_bytes(bytes)
+    );
+    println!("   {}", out.display().to_string().bright_cyan());
+    println!("{}", format!("💡 Restore with 'opj restore {}'", out.display()).bright_black());
+
+    Ok(())
+}
+
+pub async fn list() -> Result<()> {
+    let settings = Settings::load().await?;
+    let dir = settings.obsidian_path.join(BACKUP_DIR);
+    let backups = backup::list_backups(&dir);
+
+    if json_output() {
+        let entries: Vec<serde_json::Value> = backups.iter()
+            .map(|(path, manifest)| match manifest {
+                Ok(manifest) => serde_json::json!({
+                    "path": path.display().to_string(),
+                    "created": manifest.created,
+                    "projects": manifest.projects.iter().map(entry_object).collect::<Vec<_>>(),
+                    "bytes": file_size(path),
+                    "error": null,
+                }),
+                Err(e) => serde_json::json!({
+                    "path": path.display().to_string(),
+                    "created": null,
+                    "projects": [],
+                    "bytes": file_size(path),
+                    "error": format!("{:#}", e),
+                }),
+            })
+            .collect();
+        return emit_json(serde_json::json!({ "path": dir.display().to_string(), "backups": entries }));
+    }
+
+    if backups.is_empty() {
+        println!("{}", "No backups yet.".bright_yellow());
+        println!("{} Use {} to create one", "💡".bright_blue(), "'opj backup'".bright_cyan());
+        return Ok(());
+    }
+
+    println!("{} {}", "💾 Backups in".bright_cyan().bold(), dir.display().to_string().bright_cyan());
+    println!();
+    for (path, manifest) in &backups {
+        let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        match manifest {
+            Ok(manifest) => println!("  {}  {}  {} project(s), {}",
+                name.bright_white().bold(),
+                manifest.created.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string().bright_black(),
+                manifest.projects.len(),
+                format_bytes(file_size(path))
+            ),
+            Err(e) => println!("  {}  {}", name.bright_white().bold(), format!("unreadable: {:#}", e).bright_red()),
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn restore(archive: String, project: Option<String>, on_conflict: ConflictPolicy, dry_run: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    let vault_path = &settings.obsidian_path;
+    let archive_path = PathBuf::from(&archive);
+    if !archive_path.exists() {
+        return Err(error::not_found(format!("Backup not found: {}", archive)));
+    }
+
+    let mut manifest = backup::read_manifest(&archive_path)?;
+    if let Some(name) = &project {
+        let entry = manifest.find(name)
+            .cloned()
+            .ok_or_else(|| error::not_found(format!("Project '{}' is not in this backup", name)))?;
+        manifest.projects = vec![entry];
+    }
+
+    let current = ProjectScanner::from_settings(&settings)?
+        .include_archived(true)
+        .scan_projects()
+        .await?;
+    let on_conflict: OnConflict = on_conflict.into();
+    let plan = backup::plan_restore(&manifest, vault_path, &current, on_conflict);
+
+    let conflicts: Vec<&RestoreItem> = plan.iter().filter(|item| item.conflict.is_some()).collect();
+    if on_conflict == OnConflict::Fail && !conflicts.is_empty() {
+        let details: Vec<String> = conflicts.iter()
+            .map(|item| format!("  {}: {}", item.entry.name, item.conflict.as_deref().unwrap_or_default()))
+            .collect();
+        return Err(error::conflict(format!(
+            "{} project(s) already exist; nothing was restored (use --on-conflict skip or overwrite):\n{}",
+            conflicts.len(),
+            details.join("\n")
+        )));
+    }
+
+    let folders: Vec<&str> = plan.iter()
+        .filter(|item| item.action != RestoreAction::Skip)
+        .map(|item| item.entry.path.as_str())
+        .collect();
+    let files = if dry_run || folders.is_empty() {
+        0
+    } else {
+        backup::extract_projects(&archive_path, vault_path, &folders)?
+    };
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "archive": archive_path.display().to_string(),
+            "dry_run": dry_run,
+            "projects": plan.iter()
+                .map(|item| {
+                    let mut object = entry_object(&item.entry);
+                    object["action"] = serde_json::json!(item.action);
+                    object["conflict"] = serde_json::json!(item.conflict);
+                    object
+                })
+                .collect::<Vec<_>>(),
+            "files": files,
+        }));
+    }
+
+    println!("{} Restoring from {}", "📂".bright_blue(), archive_path.display().to_string().bright_cyan());
+    println!("   Backup from {}", manifest.created.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string().bright_black());
+    println!();
+    for item in &plan {
+        let (icon, label) = match item.action {
+            RestoreAction::Restore => ("✓".bright_green(), "restore".bright_green()),
+            RestoreAction::Overwrite => ("✓".bright_yellow(), "overwrite".bright_yellow()),
+            RestoreAction::Skip => ("•".bright_black(), "skip".bright_black()),
+        };
+        print!("  {} {} ({}) → {}", icon, item.entry.name.bright_white(), item.entry.path.bright_black(), label);
+        match &item.conflict {
+            Some(conflict) => println!(": {}", conflict.bright_black()),
+            None => println!(),
+        }
+    }
+
+    println!();
+    if dry_run {
+        println!("{} Dry run: nothing was restored", "ℹ️".bright_blue());
+    } else {
+        println!("{} Restored {} project(s), {} files", "✅".bright_green(), folders.len(), files);
+    }
+
+    Ok(())
+}
+
+fn entry_object(entry: &BackupEntry) -> serde_json::Value {
+    serde_json::json!({
+        "id": entry.id,
+        "name": entry.name,
+        "status": entry.status,
+        "path": entry.path,
+        "files": entry.files,
+    })
+}
+
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
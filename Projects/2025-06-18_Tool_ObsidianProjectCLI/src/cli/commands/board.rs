@@ -0,0 +1,161 @@
+use anyhow::Result;
+use colored::*;
+use crate::cli::ProjectStatus;
+use crate::config::Settings;
+use crate::core::{self, Project};
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::export::project_ref;
+use crate::utils::output::{emit_json, json_output, require_text_output};
+use unicode_width::UnicodeWidthStr;
+
+const MIN_COLUMN_WIDTH: usize = 18;
+const MAX_COLUMN_WIDTH: usize = 32;
+
+pub async fn execute(statuses: Vec<ProjectStatus>, interactive: bool, query: Option<String>) -> Result<()> {
+    let settings = Settings::load().await?;
+    let query = settings.parse_query(query.as_deref())?;
+
+    let columns: Vec<core::ProjectStatus> = if statuses.is_empty() {
+        core::workflow_columns(&settings.status_values)
+    } else {
+        statuses.into_iter().map(Into::into).collect()
+    };
+
+    if interactive {
+        require_text_output("board --interactive")?;
+        return crate::tui::board::run(settings, columns, query).await;
+    }
+
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let mut projects = scanner.scan_projects().await?;
+    query.apply(&mut projects);
+    let board = core::build_board(&columns, &projects);
+
+    if json_output() {
+        let shown: usize = board.iter().map(|column| column.projects.len()).sum();
+        return emit_json(serde_json::json!({
+            "columns": board.iter()
+                .map(|column| serde_json::json!({
+                    "status": column.status.to_string(),
+                    "projects": column.projects.iter()
+                        .map(|project| {
+                            let mut card = project_ref(project);
+                            card["priority"] = project.priority.to_string().into();
+                            card["days-since-update"] = project.days_since_update().into();
+                            card["stale"] = core::is_stale(project).into();
+                            card
+                        })
+                        .collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+            "hidden": projects.len() - shown,
+        }));
+    }
+
+    let terminal_width = crossterm::terminal::size()
+        .map(|(width, _)| width as usize)
+        .unwrap_or(120);
+    let width = (terminal_width.saturating_sub(columns.len()) / columns.len().max(1))
+        .clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+
+    println!("{}", "📋 Project Board".bright_cyan().bold());
+    println!();
+
+    // Column headers
+    let headers: Vec<String> = board.iter()
+        .map(|column| {
+            let title = fit(&format!("{} ({})", column.status, column.projects.len()), width);
+            pad(title.bright_white().bold().to_string(), title.width(), width)
+        })
+        .collect();
+    println!("{}", headers.join(" "));
+    println!("{}", vec!["─".repeat(width); board.len()].join(" ").bright_black());
+
+    // Cards, row by row
+    let rows = board.iter().map(|column| column.projects.len()).max().unwrap_or(0);
+    for row in 0..rows {
+        for line in 0..4 {
+            let cells: Vec<String> = board.iter()
+                .map(|column| match column.projects.get(row) {
+                    Some(project) => card_line(project, line, width),
+                    None => " ".repeat(width),
+                })
+                .collect();
+            println!("{}", cells.join(" ").trim_end());
+        }
+    }
+
+    let shown: usize = board.iter().map(|column| column.projects.len()).sum();
+    println!();
+    if shown < projects.len() {
+        println!("{} {} projects in other statuses are not shown",
+            "ℹ️".bright_blue(),
+            (projects.len() - shown).to_string().bright_white()
+        );
+    }
+    println!("{} Use {} to move cards between columns",
+        "💡".bright_blue(),
+        "'opj board --interactive'".bright_cyan()
+    );
+
+    Ok(())
+}
+
+/// One line of a project card: top border, name, priority and age, bottom border.
+fn card_line(project: &Project, line: usize, width: usize) -> String {
+    let inner = width - 4;
+    match line {
+        0 => format!("╭{}╮", "─".repeat(width - 2)).bright_black().to_string(),
+        1 => {
+            let name = fit(&project.name, inner);
+            format!("{} {} {}",
+                "│".bright_black(),
+                pad(name.bright_white().bold().to_string(), name.width(), inner),
+                "│".bright_black()
+            )
+        }
+        2 => {
+            let age = format!("{}d", project.days_since_activity());
+            let stale = core::is_stale(project);
+            let plain_len = project.priority.to_string().len() + 3 + age.len()
+                + if stale { " stale".len() } else { 0 };
+
+            let mut text = format!("{} · {}", format_priority(&project.priority), age.bright_black());
+            if stale {
+                text.push_str(&" stale".bright_red().to_string());
+            }
+            format!("{} {} {}", "│".bright_black(), pad(text, plain_len, inner), "│".bright_black())
+        }
+        _ => format!("╰{}╯", "─".repeat(width - 2)).bright_black().to_string(),
+    }
+}
+
+/// Truncate `text` to at most `width` terminal columns, marking cut text with "…".
+fn fit(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    let mut fitted = String::new();
+    for c in text.chars() {
+        if fitted.width() + c.to_string().width() + 1 > width {
+            break;
+        }
+        fitted.push(c);
+    }
+    fitted.push('…');
+    fitted
+}
+
+/// Pad an already colored string to `width` columns given its visible length.
+fn pad(text: String, visible_len: usize, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(visible_len)))
+}
+
+fn format_priority(priority: &core::Priority) -> ColoredString {
+    match priority {
+        core::Priority::High => "High".bright_red(),
+        core::Priority::Medium => "Medium".bright_yellow(),
+        core::Priority::Low => "Low".bright_blue(),
+    }
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use colored::*;
+use crate::cli::CacheCommand;
+use crate::config::Settings;
+use crate::fs::cache::ProjectIndex;
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(subcommand: CacheCommand) -> Result<()> {
+    let mut settings = Settings::load().await?;
+    let cache_path = settings.cache_path();
+
+    match subcommand {
+        CacheCommand::Rebuild => {
+            ProjectIndex::clear(&cache_path)?;
+            // Rebuilding is an explicit request, so ignore `scan.cache: false`
+            settings.scan.cache = true;
+            let projects = ProjectScanner::from_settings(&settings)?.scan_projects().await?;
+
+            if json_output() {
+                return emit_json(serde_json::json!({
+                    "path": cache_path.display().to_string(),
+                    "projects": projects.len(),
+                }));
+            }
+            println!("{} Indexed {} project(s) in {}",
+                "✅".bright_green(),
+                projects.len(),
+                cache_path.display().to_string().bright_cyan()
+            );
+        }
+        CacheCommand::Clear => {
+            let removed = ProjectIndex::clear(&cache_path)?;
+
+            if json_output() {
+                return emit_json(serde_json::json!({
+                    "path": cache_path.display().to_string(),
+                    "removed": removed,
+                }));
+            }
+            if removed {
+                println!("{} Cleared the project index", "🗑️".bright_green());
+            } else {
+                println!("{}", "No project index to clear.".bright_yellow());
+            }
+        }
+    }
+
+    Ok(())
+}
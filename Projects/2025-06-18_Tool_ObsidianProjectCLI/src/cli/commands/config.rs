@@ -1,6 +1,8 @@
 use anyhow::Result;
+use crate::utils::output::require_text_output;
 
 pub async fn execute(_key: Option<String>, _value: Option<String>) -> Result<()> {
+    require_text_output("config")?;
     println!("🚧 Configuration management coming soon!");
     println!("This will help manage CLI settings and preferences.");
     Ok(())
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use chrono::Utc;
+use colored::*;
+use crate::config::Settings;
+use crate::core::{self, Project};
+use crate::fs::operations::update_managed_regions;
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::markdown::DASHBOARD_FILE;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute() -> Result<()> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let projects = scanner.scan_projects().await?;
+
+    let note_path = settings.projects_path().join(DASHBOARD_FILE);
+    let updated = refresh(&settings, &projects).await?;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "path": note_path.display().to_string(),
+            "projects": projects.len(),
+            "updated": updated,
+        }));
+    }
+
+    if updated {
+        println!("{} Updated dashboard ({} projects) at {}",
+            "📊".bright_blue(),
+            projects.len(),
+            note_path.display().to_string().bright_cyan()
+        );
+    } else {
+        println!("{} Dashboard is already up to date",
+            "✅".bright_green()
+        );
+    }
+
+    Ok(())
+}
+
+/// Rewrite the managed regions of the dashboard note. Returns whether the
+/// note changed.
+pub async fn refresh(settings: &Settings, projects: &[Project]) -> Result<bool> {
+    let note_path = settings.projects_path().join(DASHBOARD_FILE);
+    let regions = core::render_dashboard(projects, &settings.obsidian_path, Utc::now());
+    update_managed_regions(&note_path, "Project Dashboard", &regions).await
+}
@@ -0,0 +1,156 @@
+use anyhow::{Context, Result};
+use colored::*;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use crate::config::Settings;
+use crate::core::{self, Fix, Issue, IssueKind, OverviewFields, Project, VaultState};
+use crate::fs::operations::write_string_to_file;
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::export::project_ref;
+use crate::utils::markdown::{set_frontmatter_value, OVERVIEW_FILE};
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(fix: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    // Archived projects are checked too, so relations to them resolve, but
+    // only the active ones are reported
+    let scanner = ProjectScanner::from_settings(&settings)?.include_archived(true);
+    let archive_path = settings.archive_path();
+
+    let scan = scanner.scan().await?;
+    let projects = scan.projects;
+    let failures = scan.warnings.into_iter()
+        .map(|warning| (warning.project_dir, warning.message))
+        .collect();
+
+    let overviews: BTreeMap<PathBuf, OverviewFields> = projects.iter()
+        .filter_map(|project| project.path.as_ref())
+        .filter(|dir| dir.join(core::METADATA_FILE).exists())
+        .filter_map(|dir| Some((dir.clone(), scanner::overview_fields(dir)?)))
+        .collect();
+
+    let issues = core::diagnose(&VaultState {
+        projects: &projects,
+        failures,
+        overviews,
+        templates_path: settings.templates_path(),
+        default_type: settings.default_type.clone().into(),
+    });
+    let issues: Vec<Issue> = issues.into_iter()
+        .filter(|issue| !core::is_in_archive(&archive_path, &issue.path))
+        .collect();
+    let checked = projects.iter().filter(|project| !scanner.is_archived(project)).count();
+
+    let fixed = if fix { apply_fixes(&issues, &projects).await? } else { vec![false; issues.len()] };
+
+    if json_output() {
+        let entries: Vec<serde_json::Value> = issues.iter().zip(&fixed)
+            .map(|(issue, fixed)| serde_json::json!({
+                "kind": issue.kind,
+                "path": issue.path.display().to_string(),
+                "project": project_at(&projects, issue).map(project_ref),
+                "message": issue.message,
+                "fix": issue.fix.as_ref().map(Fix::describe),
+                "fixed": fixed,
+            }))
+            .collect();
+        return emit_json(serde_json::json!({
+            "issues": entries,
+            "fixed": fixed.iter().filter(|f| **f).count(),
+        }));
+    }
+
+    println!("{}", "🩺 Vault health check".bright_cyan().bold());
+    println!();
+
+    if issues.is_empty() {
+        println!("{} No problems found in {} project(s)", "✅".bright_green(), checked);
+        return Ok(());
+    }
+
+    for (issue, fixed) in issues.iter().zip(&fixed) {
+        println!("{} {}", kind_icon(issue.kind), display_path(&issue.path, &settings.obsidian_path).bright_white());
+        println!("   {}", issue.message);
+        match (&issue.fix, fixed) {
+            (Some(fix), true) => println!("   {} {}", "✅ fixed:".bright_green(), fix.describe()),
+            (Some(fix), false) => println!("   {} {}", "🔧 fix:".bright_blue(), fix.describe().bright_black()),
+            (None, _) => println!("   {}", "✋ needs a manual fix".bright_yellow()),
+        }
+        println!();
+    }
+
+    let fixable = issues.iter().filter(|issue| issue.fix.is_some()).count();
+    let fixed_count = fixed.iter().filter(|f| **f).count();
+    println!("{} issue(s) found", issues.len().to_string().bright_white().bold());
+    if fix {
+        println!("{} Fixed {} issue(s)", "✅".bright_green(), fixed_count);
+    } else if fixable > 0 {
+        println!("{}", format!("💡 Run 'opj doctor --fix' to repair {} of them", fixable).bright_cyan());
+    }
+
+    Ok(())
+}
+
+/// Apply every available fix, one metadata write per project. Returns which
+/// issues were fixed, in order.
+async fn apply_fixes(issues: &[Issue], projects: &[Project]) -> Result<Vec<bool>> {
+    let mut fixed = vec![false; issues.len()];
+    let mut changed: BTreeMap<PathBuf, Project> = BTreeMap::new();
+
+    for (i, issue) in issues.iter().enumerate() {
+        let (Some(fix), Some(dir)) = (&issue.fix, &issue.project_dir) else { continue };
+        let Some(original) = projects.iter().find(|p| p.path.as_ref() == Some(dir)) else { continue };
+
+        if let Fix::SetOverview { key, value } = fix {
+            let overview_path = dir.join(OVERVIEW_FILE);
+            let content = tokio::fs::read_to_string(&overview_path).await
+                .with_context(|| format!("Failed to read {}", overview_path.display()))?;
+            write_string_to_file(&overview_path, &set_frontmatter_value(&content, key, value)).await?;
+            fixed[i] = true;
+            continue;
+        }
+
+        let project = changed.entry(dir.clone()).or_insert_with(|| original.clone());
+        match fix {
+            Fix::NewId => project.id = uuid::Uuid::new_v4().to_string(),
+            Fix::DropRelation { id } => {
+                if project.parent.as_ref() == Some(id) {
+                    project.parent = None;
+                }
+                project.depends_on.retain(|other| other != id);
+                project.related.retain(|other| other != id);
+            }
+            Fix::RecordStatus => project.status_history.push(core::StatusEntry {
+                status: project.status.clone(),
+                timestamp: chrono::Utc::now(),
+                note: Some("Recorded by opj doctor".to_string()),
+            }),
+            Fix::SetOverview { .. } => unreachable!(),
+        }
+        fixed[i] = true;
+    }
+
+    for (dir, project) in &mut changed {
+        core::save_project_metadata(project, dir).await?;
+    }
+
+    Ok(fixed)
+}
+
+fn project_at<'a>(projects: &'a [Project], issue: &Issue) -> Option<&'a Project> {
+    let dir = issue.project_dir.as_ref()?;
+    projects.iter().find(|project| project.path.as_ref() == Some(dir))
+}
+
+fn display_path(path: &Path, vault_path: &Path) -> String {
+    path.strip_prefix(vault_path).unwrap_or(path).display().to_string()
+}
+
+fn kind_icon(kind: IssueKind) -> ColoredString {
+    let label = format!("[{}]", kind);
+    match kind {
+        IssueKind::InvalidMetadata | IssueKind::DuplicateId => format!("❌ {}", label).bright_red(),
+        IssueKind::MissingTemplate => format!("📄 {}", label).bright_yellow(),
+        _ => format!("⚠️ {}", label).bright_yellow(),
+    }
+}
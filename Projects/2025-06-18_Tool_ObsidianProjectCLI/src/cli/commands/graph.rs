@@ -0,0 +1,105 @@
+use anyhow::Result;
+use colored::*;
+use std::path::PathBuf;
+use crate::cli::{GraphFormat, ProjectStatus, ProjectType};
+use crate::config::Settings;
+use crate::core;
+use crate::fs::operations::{update_managed_regions, write_string_to_file};
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::markdown::DASHBOARD_FILE;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(
+    format: GraphFormat,
+    status_filter: Option<ProjectStatus>,
+    type_filter: Option<ProjectType>,
+    output: Option<String>,
+    dashboard: Option<String>,
+) -> Result<()> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?.include_archived(true);
+    let all = scanner.scan_projects().await?;
+    let mut projects: Vec<core::Project> = all.iter().filter(|p| !scanner.is_archived(p)).cloned().collect();
+
+    if let Some(status) = status_filter {
+        let status: core::ProjectStatus = status.into();
+        projects.retain(|p| p.status == status);
+    }
+
+    if let Some(project_type) = type_filter {
+        let project_type: core::ProjectType = project_type.into();
+        projects.retain(|p| p.project_type == project_type);
+    }
+
+    // Archived projects are only drawn when a shown project links to them
+    let linked: std::collections::HashSet<&String> = projects.iter()
+        .flat_map(|p| p.depends_on.iter().chain(&p.related))
+        .collect();
+    let archived: Vec<core::Project> = all.iter()
+        .filter(|p| scanner.is_archived(p) && linked.contains(&p.id))
+        .cloned()
+        .collect();
+    projects.extend(archived);
+
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mermaid = core::render_mermaid(&projects);
+    let rendered = match format {
+        GraphFormat::Dot => core::render_dot(&projects),
+        GraphFormat::Mermaid => format!("```mermaid\n{}```\n", mermaid),
+    };
+
+    let to_dashboard = dashboard.is_some();
+    let mut written = Vec::new();
+    if let Some(note) = dashboard {
+        let note_path = if note.is_empty() {
+            settings.projects_path().join(DASHBOARD_FILE)
+        } else {
+            let mut path = settings.obsidian_path.join(&note);
+            if path.extension().is_none() {
+                path.set_extension("md");
+            }
+            path
+        };
+
+        let body = format!("## Project Graph\n\n```mermaid\n{}```", mermaid);
+        update_managed_regions(&note_path, "Project Dashboard", &[("graph", body)]).await?;
+        written.push(note_path.display().to_string());
+        if !json_output() {
+            println!("{} Wrote project graph ({} projects) to {}",
+                "🕸️".bright_blue(),
+                projects.len(),
+                note_path.display().to_string().bright_cyan()
+            );
+        }
+    }
+
+    if let Some(output) = output {
+        let output_path = PathBuf::from(&output);
+        write_string_to_file(&output_path, &rendered).await?;
+        written.push(output_path.display().to_string());
+        if !json_output() {
+            println!("{} Wrote project graph ({} projects) to {}",
+                "🕸️".bright_blue(),
+                projects.len(),
+                output_path.display().to_string().bright_cyan()
+            );
+        }
+    } else if !to_dashboard && !json_output() {
+        print!("{}", rendered);
+    }
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "format": match format {
+                GraphFormat::Dot => "dot",
+                GraphFormat::Mermaid => "mermaid",
+            },
+            "projects": projects.len(),
+            "content": rendered,
+            "written_to": written,
+        }));
+    }
+
+    Ok(())
+}
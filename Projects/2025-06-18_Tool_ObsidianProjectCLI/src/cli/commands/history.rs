@@ -0,0 +1,123 @@
+use anyhow::Result;
+use colored::*;
+use crate::config::Settings;
+use crate::fs::history::{self, Change, Operation};
+use crate::utils::error;
+use crate::utils::markdown::vault_relative;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn history(limit: usize) -> Result<()> {
+    let settings = Settings::load().await?;
+    let history_path = settings.obsidian_path.join(history::HISTORY_DIR);
+    let operations: Vec<Operation> = history::operations(&history_path).into_iter().take(limit).collect();
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "operations": operations.iter().map(|op| operation_object(&settings, op)).collect::<Vec<_>>(),
+        }));
+    }
+
+    if operations.is_empty() {
+        println!("{}", "No changes recorded yet.".bright_yellow());
+        return Ok(());
+    }
+
+    println!("{}", "🕘 Recent changes".bright_cyan().bold());
+    println!();
+    for operation in &operations {
+        let line = format!("  {}  {:<10} {} change(s)",
+            operation.started.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S"),
+            operation.command,
+            operation.changes.len()
+        );
+        match operation.undone {
+            true => println!("{} {}", line.bright_black(), "(undone)".bright_black()),
+            false => println!("{}", line),
+        }
+        for change in &operation.changes {
+            println!("      {}", describe(&settings, change).bright_black());
+        }
+    }
+    println!();
+    println!("{}", "💡 Revert the latest with 'opj undo'".bright_black());
+
+    Ok(())
+}
+
+pub async fn undo(count: usize, force: bool, dry_run: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    let history_path = settings.obsidian_path.join(history::HISTORY_DIR);
+    let operations: Vec<Operation> = history::operations(&history_path)
+        .into_iter()
+        .filter(|operation| !operation.undone)
+        .take(count)
+        .collect();
+
+    if operations.is_empty() {
+        return Err(error::not_found("Nothing to undo"));
+    }
+
+    let conflicts: Vec<String> = operations.iter()
+        .enumerate()
+        .flat_map(|(i, operation)| operation.undo_conflicts(&operations[..i])
+            .into_iter()
+            .map(move |conflict| format!("  {}: {}", operation.command, conflict)))
+        .collect();
+    if !force && !conflicts.is_empty() {
+        return Err(error::conflict(format!(
+            "Files were changed after opj wrote them; nothing was undone (use --force to revert anyway):\n{}",
+            conflicts.join("\n")
+        )));
+    }
+
+    if !dry_run {
+        for operation in &operations {
+            history::undo(&history_path, operation)?;
+        }
+    }
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "dry_run": dry_run,
+            "operations": operations.iter().map(|op| operation_object(&settings, op)).collect::<Vec<_>>(),
+        }));
+    }
+
+    for operation in &operations {
+        println!("{} Undid {} from {}",
+            "↩️".bright_blue(),
+            operation.command.bright_white().bold(),
+            operation.started.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string().bright_black()
+        );
+        for change in &operation.changes {
+            println!("   {}", describe(&settings, change).bright_black());
+        }
+    }
+
+    if dry_run {
+        println!();
+        println!("{} Dry run: nothing was reverted", "ℹ️".bright_blue());
+    }
+
+    Ok(())
+}
+
+fn describe(settings: &Settings, change: &Change) -> String {
+    let vault = &settings.obsidian_path;
+    match change {
+        Change::Write { path, backup: Some(_), .. } => format!("edited {}", vault_relative(path, vault)),
+        Change::Write { path, backup: None, .. } => format!("created {}", vault_relative(path, vault)),
+        Change::Move { from, to } => format!("moved {} → {}", vault_relative(from, vault), vault_relative(to, vault)),
+        Change::CreateDir { path, .. } => format!("created folder {}", vault_relative(path, vault)),
+    }
+}
+
+fn operation_object(settings: &Settings, operation: &Operation) -> serde_json::Value {
+    serde_json::json!({
+        "id": operation.id,
+        "command": operation.command,
+        "started": operation.started,
+        "changes": operation.changes.iter().map(|change| describe(settings, change)).collect::<Vec<_>>(),
+        "undone": operation.undone,
+    })
+}
@@ -1,25 +1,71 @@
 use anyhow::Result;
 use colored::*;
+use crate::cli::{Column, OutputFormat};
 use crate::config::Settings;
-use crate::fs::scanner::ProjectScanner;
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::date::format_duration_hm;
+use crate::utils::export::{
+    all_columns, project_object, project_ref, render_records, round_tenths, status_history, Records,
+};
+use crate::utils::error;
+use crate::utils::output::{emit_json, json_output};
 
-pub async fn execute(project_name: String) -> Result<()> {
+pub async fn execute(project_name: String, format: OutputFormat, columns: Vec<Column>) -> Result<()> {
     let settings = Settings::load().await?;
-    let scanner = ProjectScanner::new(settings.projects_path());
+    let scanner = ProjectScanner::from_settings(&settings)?;
     
     // Find the project
-    let project = scanner.find_project_by_name(&project_name).await?
-        .ok_or_else(|| anyhow::anyhow!("Project '{}' not found", project_name))?;
+    let projects = scanner.scan_projects().await?;
+    let project = scanner::find_by_name(&projects, &project_name)
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", project_name)))?;
+
+    if json_output() {
+        return emit_json(info_json(project, &projects));
+    }
+
+    if !matches!(format, OutputFormat::Table) {
+        let columns = if columns.is_empty() { all_columns() } else { columns };
+        let records = Records::from_projects(std::slice::from_ref(project), &columns);
+        print!("{}", render_records(&records, &format)?);
+        return Ok(());
+    }
 
     // Display detailed project information
-    print_project_header(&project)?;
-    print_project_details(&project)?;
-    print_project_files(&project).await?;
-    print_project_statistics(&project)?;
+    print_project_header(project)?;
+    print_project_details(project)?;
+    print_project_relations(project, &projects)?;
+    print_project_files(project).await?;
+    print_project_statistics(project)?;
 
     Ok(())
 }
 
+fn info_json(project: &crate::core::Project, projects: &[crate::core::Project]) -> serde_json::Value {
+    let refs = |related: Vec<&crate::core::Project>| -> Vec<serde_json::Value> {
+        related.into_iter().map(project_ref).collect()
+    };
+    let by_ids = |ids: &[String]| -> Vec<&crate::core::Project> {
+        ids.iter().filter_map(|id| crate::core::find_by_id(projects, id)).collect()
+    };
+
+    serde_json::json!({
+        "project": project_object(project),
+        "status_history": status_history(project),
+        "relations": {
+            "parent": project.parent.as_deref()
+                .and_then(|id| crate::core::find_by_id(projects, id))
+                .map(project_ref),
+            "children": refs(crate::core::children(project, projects)),
+            "depends_on": refs(by_ids(&project.depends_on)),
+            "required_by": refs(crate::core::dependents(project, projects)),
+            "related": refs(by_ids(&project.related)),
+            "blocked_by": refs(crate::core::blocking_dependencies(project, projects)),
+        },
+        "rollup_progress": round_tenths(crate::core::rollup_progress(project, projects)),
+        "time_logged_minutes": project.total_time_logged().num_minutes(),
+    })
+}
+
 fn print_project_header(project: &crate::core::Project) -> Result<()> {
     let type_emoji = match &project.project_type {
         crate::core::ProjectType::WebApp => "🌐",
@@ -96,6 +142,60 @@ fn print_project_details(project: &crate::core::Project) -> Result<()> {
     Ok(())
 }
 
+fn print_project_relations(project: &crate::core::Project, projects: &[crate::core::Project]) -> Result<()> {
+    let depends_on: Vec<&crate::core::Project> = project.depends_on.iter()
+        .filter_map(|id| crate::core::find_by_id(projects, id))
+        .collect();
+    let required_by = crate::core::dependents(project, projects);
+    let related: Vec<&crate::core::Project> = project.related.iter()
+        .filter_map(|id| crate::core::find_by_id(projects, id))
+        .collect();
+    let parent = project.parent.as_deref()
+        .and_then(|id| crate::core::find_by_id(projects, id));
+    let sub_projects = crate::core::children(project, projects);
+
+    if depends_on.is_empty() && required_by.is_empty() && related.is_empty()
+        && parent.is_none() && sub_projects.is_empty()
+    {
+        return Ok(());
+    }
+
+    println!("{}", "🔗 Relations".bright_blue().bold());
+    if let Some(parent) = parent {
+        println!("  Parent: {}", parent.name.bright_white());
+    }
+    for child in &sub_projects {
+        println!("  Sub-project: {} ({})", child.name.bright_white(), format_status(&child.status));
+    }
+    if !sub_projects.is_empty() {
+        println!("  Rolled-up Progress: {}%",
+            format!("{:.0}", crate::core::rollup_progress(project, projects)).bright_green()
+        );
+    }
+    for dep in &depends_on {
+        let marker = if dep.satisfies_dependency() { "✅" } else { "⛔" };
+        println!("  Depends on: {} {} ({})", marker, dep.name.bright_white(), format_status(&dep.status));
+    }
+    for dependent in &required_by {
+        println!("  Required by: {}", dependent.name.bright_white());
+    }
+    for other in &related {
+        println!("  Related: {}", other.name.bright_white());
+    }
+
+    let blocking = crate::core::blocking_dependencies(project, projects);
+    if !blocking.is_empty() {
+        println!("  {} Blocked by {} unfinished {}",
+            "⚠️".bright_yellow(),
+            blocking.len().to_string().bright_red(),
+            if blocking.len() == 1 { "dependency" } else { "dependencies" }
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
 async fn print_project_files(project: &crate::core::Project) -> Result<()> {
     if let Some(project_path) = &project.path {
         println!("{}", "📁 Project Files".bright_blue().bold());
@@ -178,6 +278,22 @@ fn print_project_statistics(project: &crate::core::Project) -> Result<()> {
     };
     
     println!("  Activity: {}", activity_status);
+
+    // Time tracking
+    if !project.time_entries.is_empty() {
+        println!("  Time Logged: {} ({} {})",
+            format_duration_hm(&project.total_time_logged()).bright_cyan(),
+            project.time_entries.len(),
+            if project.time_entries.len() == 1 { "session" } else { "sessions" }
+        );
+    }
+
+    if let Some(entry) = project.active_time_entry() {
+        println!("  Tracking: {} (since {})",
+            format_duration_hm(&entry.duration()).bright_yellow(),
+            entry.start.with_timezone(&chrono::Local).format("%H:%M")
+        );
+    }
     
     // Time in current status
     if let Some(current_entry) = project.status_history.last() {
@@ -0,0 +1,157 @@
+use anyhow::Result;
+use colored::*;
+use std::path::PathBuf;
+use crate::cli::ProjectStatus;
+use crate::cli::commands::status::apply_status_change;
+use crate::config::Settings;
+use crate::core;
+use crate::fs::operations::{read_file_to_string, write_string_to_file};
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::error;
+use crate::utils::export::project_ref;
+use crate::utils::output::{emit_json, json_output};
+
+const DEFAULT_BOARD_NOTE: &str = "Board.md";
+
+pub async fn export(out: Option<String>, statuses: Vec<ProjectStatus>) -> Result<()> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let projects = scanner.scan_projects().await?;
+
+    let columns: Vec<core::ProjectStatus> = if statuses.is_empty() {
+        core::workflow_columns(&settings.status_values)
+    } else {
+        statuses.into_iter().map(Into::into).collect()
+    };
+
+    let board = core::build_board(&columns, &projects);
+    let note = core::render_kanban(&board, &settings.obsidian_path);
+
+    let note_path = board_note_path(&settings, out);
+    write_string_to_file(&note_path, &note).await?;
+
+    let cards: usize = board.iter().map(|column| column.projects.len()).sum();
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "path": note_path.display().to_string(),
+            "projects": cards,
+            "lanes": board.len(),
+        }));
+    }
+
+    println!("{} Exported {} projects in {} lanes to {}",
+        "📋".bright_blue(),
+        cards.to_string().bright_white(),
+        board.len().to_string().bright_white(),
+        note_path.display().to_string().bright_cyan()
+    );
+    println!("{} Move cards in Obsidian, then run {} to update statuses",
+        "💡".bright_blue(),
+        "'opj import kanban'".bright_cyan()
+    );
+
+    Ok(())
+}
+
+pub async fn import(file: Option<String>, dry_run: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let mut projects = scanner.scan_projects().await?;
+
+    let note_path = board_note_path(&settings, file);
+    if !note_path.exists() {
+        return Err(error::not_found(format!("Board note not found: {}", note_path.display())));
+    }
+    let lanes = core::parse_kanban(&read_file_to_string(&note_path).await?);
+
+    // Work out the target status of each card before touching anything
+    let mut changes: Vec<(String, core::ProjectStatus)> = Vec::new();
+    let mut warnings = Vec::new();
+    let mut warn = |message: String| {
+        if !json_output() {
+            println!("{} {}", "⚠️".bright_yellow(), message);
+        }
+        warnings.push(message);
+    };
+    for lane in &lanes {
+        let status: core::ProjectStatus = match lane.title.parse() {
+            Ok(status) => status,
+            Err(_) => {
+                if !lane.cards.is_empty() {
+                    warn(format!("Skipping lane \"{}\": not a project status", lane.title));
+                }
+                continue;
+            }
+        };
+
+        for card in &lane.cards {
+            let Some(project) = core::resolve_card(card, &projects) else {
+                warn(format!("No project found for card \"{}\"", card.text));
+                continue;
+            };
+            if changes.iter().any(|(id, _)| *id == project.id) {
+                warn(format!("\"{}\" appears in more than one lane; keeping the first", project.name));
+                continue;
+            }
+            if project.status != status {
+                changes.push((project.id.clone(), status.clone()));
+            }
+        }
+    }
+
+    if changes.is_empty() && !json_output() {
+        println!("{} All project statuses already match the board", "✅".bright_green());
+        return Ok(());
+    }
+
+    let mut applied = Vec::new();
+    for (id, status) in changes {
+        let Some(project) = projects.iter_mut().find(|p| p.id == id) else {
+            continue;
+        };
+        let old_status = project.status.clone();
+
+        if !dry_run {
+            apply_status_change(project, status.clone(), Some("Moved on Kanban board".to_string())).await?;
+        }
+        if json_output() {
+            applied.push(serde_json::json!({
+                "project": project_ref(project),
+                "from": old_status.to_string(),
+                "to": status.to_string(),
+            }));
+            continue;
+        }
+        println!("  {} {}: {} → {}",
+            if dry_run { "•" } else { "✓" },
+            project.name.bright_white(),
+            old_status.to_string().bright_black(),
+            status.to_string().bright_green()
+        );
+    }
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "path": note_path.display().to_string(),
+            "dry_run": dry_run,
+            "changes": applied,
+            "warnings": warnings,
+        }));
+    }
+
+    if dry_run {
+        println!();
+        println!("{} Dry run: no projects were changed", "ℹ️".bright_blue());
+    }
+
+    Ok(())
+}
+
+/// Board note location: relative paths are resolved against the vault.
+fn board_note_path(settings: &Settings, path: Option<String>) -> PathBuf {
+    match path {
+        Some(path) => settings.obsidian_path.join(path),
+        None => settings.projects_path().join(DEFAULT_BOARD_NOTE),
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::{Result, Context};
+use colored::*;
+use std::path::Path;
+use crate::cli::LinkKind;
+use crate::config::Settings;
+use crate::core::{self, Project, RelationKind};
+use crate::fs::operations::write_string_to_file;
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::markdown::{replace_managed_region, OVERVIEW_FILE};
+use crate::utils::error;
+use crate::utils::export::project_ref;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(from: String, to: String, kind: LinkKind, remove: bool) -> Result<()> {
+    let settings = Settings::load().await?;
+    // Archived projects can still be linked to and take part in cycle checks
+    let scanner = ProjectScanner::from_settings(&settings)?.include_archived(true);
+    let mut projects = scanner.scan_projects().await?;
+
+    let mut from_project = scanner::find_by_name(&projects, &from)
+        .cloned()
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", from)))?;
+    let mut to_project = scanner::find_by_name(&projects, &to)
+        .cloned()
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", to)))?;
+
+    if from_project.id == to_project.id {
+        return Err(error::invalid_argument("A project cannot be linked to itself"));
+    }
+
+    let kind: RelationKind = kind.into();
+
+    if !remove && kind == RelationKind::DependsOn {
+        if let Some(cycle) = core::find_dependency_cycle(&projects, &from_project.id, &to_project.id) {
+            let names: Vec<&str> = std::iter::once(from_project.name.as_str())
+                .chain(cycle.iter().map(|id| {
+                    core::find_by_id(&projects, id).map(|p| p.name.as_str()).unwrap_or(id)
+                }))
+                .collect();
+            return Err(error::conflict(format!(
+                "Linking would create a dependency cycle: {}",
+                names.join(" → ")
+            )));
+        }
+    }
+
+    if !remove && kind == RelationKind::Parent {
+        if let Some(chain) = core::find_parent_cycle(&projects, &from_project.id, &to_project.id) {
+            let names: Vec<&str> = std::iter::once(from_project.name.as_str())
+                .chain(chain.iter().map(|id| {
+                    core::find_by_id(&projects, id).map(|p| p.name.as_str()).unwrap_or(id)
+                }))
+                .collect();
+            return Err(error::conflict(format!(
+                "Linking would create a parent cycle: {}",
+                names.join(" → ")
+            )));
+        }
+    }
+
+    let previous_parent = from_project.parent.clone();
+
+    let changed = if remove {
+        let changed = from_project.remove_relation(kind, &to_project.id);
+        if kind == RelationKind::Related {
+            to_project.remove_relation(kind, &from_project.id);
+        }
+        changed
+    } else {
+        let changed = from_project.add_relation(kind, &to_project.id);
+        if kind == RelationKind::Related {
+            to_project.add_relation(kind, &from_project.id);
+        }
+        changed
+    };
+
+    if !changed {
+        if json_output() {
+            return emit_json(link_json(&from_project, &to_project, kind, remove, false));
+        }
+        if remove {
+            println!("{} \"{}\" is not linked to \"{}\"", "ℹ️".bright_blue(), from_project.name, to_project.name);
+        } else {
+            println!("{} \"{}\" already {} \"{}\"", "ℹ️".bright_blue(), from_project.name, kind, to_project.name);
+        }
+        return Ok(());
+    }
+
+    for updated in [&mut from_project, &mut to_project] {
+        if let Some(project_path) = updated.path.clone() {
+            core::save_project_metadata(updated, &project_path).await?;
+        }
+        if let Some(existing) = projects.iter_mut().find(|p| p.id == updated.id) {
+            *existing = updated.clone();
+        }
+    }
+
+    // Keep the generated relations section of both overview notes current
+    for updated in [&from_project, &to_project] {
+        write_relations_note(updated, &projects, &settings.obsidian_path).await?;
+    }
+
+    // A sub-project that moved to a new parent disappears from the old one
+    if kind == RelationKind::Parent {
+        if let Some(old_parent) = previous_parent
+            .filter(|id| *id != to_project.id)
+            .and_then(|id| core::find_by_id(&projects, &id))
+        {
+            write_relations_note(old_parent, &projects, &settings.obsidian_path).await?;
+        }
+    }
+
+    if json_output() {
+        return emit_json(link_json(&from_project, &to_project, kind, remove, true));
+    }
+
+    println!("{} {} \"{}\" {} \"{}\"",
+        "🔗".bright_blue(),
+        if remove { "Unlinked:" } else { "Linked:" },
+        from_project.name.bright_white().bold(),
+        kind.to_string().bright_cyan(),
+        to_project.name.bright_white().bold()
+    );
+
+    if !remove && kind == RelationKind::DependsOn && !to_project.satisfies_dependency() {
+        println!("{} \"{}\" is blocked until \"{}\" is completed ({})",
+            "⚠️".bright_yellow(),
+            from_project.name,
+            to_project.name,
+            to_project.status.to_string().bright_yellow()
+        );
+    }
+
+    Ok(())
+}
+
+fn link_json(from: &Project, to: &Project, kind: RelationKind, removed: bool, changed: bool) -> serde_json::Value {
+    let kind = match kind {
+        RelationKind::DependsOn => "depends-on",
+        RelationKind::Related => "related",
+        RelationKind::Parent => "parent",
+    };
+
+    serde_json::json!({
+        "from": project_ref(from),
+        "to": project_ref(to),
+        "kind": kind,
+        "removed": removed,
+        "changed": changed,
+        "blocked": !removed && kind == "depends-on" && !to.satisfies_dependency(),
+    })
+}
+
+/// Rewrite the generated relations region in a project's overview note.
+/// Projects without an overview note are left alone.
+pub async fn write_relations_note(project: &Project, projects: &[Project], vault_path: &Path) -> Result<()> {
+    let Some(project_path) = &project.path else {
+        return Ok(());
+    };
+
+    let overview_path = project_path.join(OVERVIEW_FILE);
+    if !overview_path.exists() {
+        return Ok(());
+    }
+
+    let content = tokio::fs::read_to_string(&overview_path).await
+        .with_context(|| format!("Failed to read {}", overview_path.display()))?;
+    let section = core::render_relations_section(project, projects, vault_path);
+    let updated = replace_managed_region(&content, "relations", section.as_deref());
+
+    if updated != content {
+        write_string_to_file(&overview_path, &updated).await?;
+    }
+
+    Ok(())
+}
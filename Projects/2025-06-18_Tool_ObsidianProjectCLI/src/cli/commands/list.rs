@@ -1,50 +1,60 @@
 use anyhow::Result;
 use colored::*;
-use tabled::{Table, Tabled};
+use tabled::builder::Builder;
 
-use crate::cli::{ProjectType, ProjectStatus, Priority, SortField, OutputFormat};
+use crate::cli::{ProjectType, ProjectStatus, Priority, SortField, OutputFormat, Column};
 use crate::core::Project;
 use crate::config::Settings;
 use crate::fs::scanner::ProjectScanner;
+use crate::utils::export::{
+    cell_text, column_title, column_value, project_object, render_project_template, render_records, Records,
+    DEFAULT_COLUMNS,
+};
+use crate::utils::output::{emit_json_with_warnings, json_output, print_scan_warnings, truncate_string};
 
-#[derive(Tabled)]
-struct ProjectRow {
-    #[tabled(rename = "Name")]
-    name: String,
-    #[tabled(rename = "Type")]
-    project_type: String,
-    #[tabled(rename = "Priority")]
-    priority: String,
-    #[tabled(rename = "Status")]
-    status: String,
-    #[tabled(rename = "Updated")]
-    updated: String,
-}
+/// Columns of the default table view
+const TABLE_COLUMNS: [Column; 5] = [
+    Column::Name,
+    Column::Type,
+    Column::Priority,
+    Column::Status,
+    Column::Updated,
+];
+/// Longer names are cut off in the table
+const NAME_WIDTH: usize = 40;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     status_filter: Option<ProjectStatus>,
     type_filter: Option<ProjectType>,
     priority_filter: Option<Priority>,
+    query: Option<String>,
     sort: SortField,
     format: OutputFormat,
+    columns: Vec<Column>,
+    template: Option<String>,
+    tree: bool,
+    strict: bool,
+    include_archived: bool,
 ) -> Result<()> {
     let settings = Settings::load().await?;
-    let scanner = ProjectScanner::new(settings.projects_path());
-    
-    let mut projects = scanner.scan_projects().await?;
-
-    // Apply filters
+    let mut query = settings.parse_query(query.as_deref())?;
     if let Some(status) = status_filter {
-        projects.retain(|p| std::mem::discriminant(&p.status) == std::mem::discriminant(&status));
+        query = query.with_status(status.into());
     }
-
     if let Some(project_type) = type_filter {
-        projects.retain(|p| std::mem::discriminant(&p.project_type) == std::mem::discriminant(&project_type));
+        query = query.with_type(&project_type.into());
     }
-
     if let Some(priority) = priority_filter {
-        projects.retain(|p| std::mem::discriminant(&p.priority) == std::mem::discriminant(&priority));
+        query = query.with_priority(priority.into());
     }
+    let scanner = ProjectScanner::from_settings(&settings)?.include_archived(include_archived);
+    
+    let scan = scanner.scan().await?;
+    scan.ensure_complete(strict)?;
+    let all_projects = scan.projects;
+    let mut projects = all_projects.clone();
+    query.apply(&mut projects);
 
     // Sort projects
     projects.sort_by(|a, b| {
@@ -58,16 +68,46 @@ pub async fn execute(
         }
     });
 
-    match format {
-        OutputFormat::Table => print_table(&projects).await?,
-        OutputFormat::Json => print_json(&projects).await?,
-        OutputFormat::Yaml => print_yaml(&projects).await?,
+    if json_output() {
+        return emit_json_with_warnings(projects_json(&projects, &columns), &scan.warnings);
+    }
+
+    if tree {
+        print_tree(&projects, &all_projects)?;
+    } else if let Some(template) = template {
+        for project in &projects {
+            println!("{}", render_project_template(&template, project));
+        }
+    } else {
+        match format {
+            OutputFormat::Table => {
+                let columns = if columns.is_empty() { TABLE_COLUMNS.to_vec() } else { columns };
+                print_table(&projects, &columns).await?
+            }
+            // Full project objects unless specific columns were requested
+            OutputFormat::Json if columns.is_empty() => print_json(&projects).await?,
+            OutputFormat::Yaml if columns.is_empty() => print_yaml(&projects).await?,
+            format => {
+                let columns = if columns.is_empty() { DEFAULT_COLUMNS.to_vec() } else { columns };
+                print!("{}", render_records(&Records::from_projects(&projects, &columns), &format)?);
+            }
+        }
     }
 
+    print_scan_warnings(&scan.warnings);
     Ok(())
 }
 
-async fn print_table(projects: &[Project]) -> Result<()> {
+/// Full project objects, or only the requested columns.
+fn projects_json(projects: &[Project], columns: &[Column]) -> serde_json::Value {
+    if columns.is_empty() {
+        return projects.iter().map(project_object).collect();
+    }
+
+    Records::from_projects(projects, columns).objects().into()
+}
+
+async fn print_table(projects: &[Project], columns: &[Column]) -> Result<()> {
     if projects.is_empty() {
         println!("{}", "No projects found matching the criteria.".bright_yellow());
         return Ok(());
@@ -90,21 +130,13 @@ async fn print_table(projects: &[Project]) -> Result<()> {
     );
     println!();
 
-    // Convert to table rows
-    let rows: Vec<ProjectRow> = projects.iter().map(|project| {
-        ProjectRow {
-            name: format!("{} {}", 
-                get_type_emoji(&project.project_type),
-                project.name
-            ),
-            project_type: format_project_type(&project.project_type),
-            priority: format_priority(&project.priority),
-            status: format_status(&project.status),
-            updated: format_relative_time(&project.updated),
-        }
-    }).collect();
+    let mut builder = Builder::default();
+    builder.push_record(columns.iter().map(column_title));
+    for project in projects {
+        builder.push_record(columns.iter().map(|column| format_cell(project, column)));
+    }
 
-    let table = Table::new(rows)
+    let table = builder.build()
         .with(tabled::settings::Style::rounded())
         .to_string();
 
@@ -124,6 +156,77 @@ async fn print_table(projects: &[Project]) -> Result<()> {
     Ok(())
 }
 
+fn print_tree(projects: &[Project], all_projects: &[Project]) -> Result<()> {
+    if projects.is_empty() {
+        println!("{}", "No projects found matching the criteria.".bright_yellow());
+        return Ok(());
+    }
+
+    println!("{} ({} projects)",
+        "🌳 Project Tree".bright_cyan().bold(),
+        projects.len().to_string().bright_white()
+    );
+    println!();
+
+    // Projects whose parent is filtered out are shown at the top level
+    let is_listed = |id: &str| projects.iter().any(|p| p.id == id);
+    let mut visited = std::collections::HashSet::new();
+
+    for root in projects.iter().filter(|p| !p.parent.as_deref().is_some_and(is_listed)) {
+        print_tree_node(root, projects, all_projects, "", None, &mut visited);
+    }
+    // Projects in a parent cycle have no root above them; start from the first one
+    for project in projects {
+        if !visited.contains(&project.id) {
+            print_tree_node(project, projects, all_projects, "", None, &mut visited);
+        }
+    }
+
+    println!();
+    println!("{} Use {} to nest a project under another",
+        "💡".bright_blue(),
+        "'opj link <child> <parent> --kind parent'".bright_cyan()
+    );
+
+    Ok(())
+}
+
+fn print_tree_node(
+    project: &Project,
+    projects: &[Project],
+    all_projects: &[Project],
+    prefix: &str,
+    is_last: Option<bool>,
+    visited: &mut std::collections::HashSet<String>,
+) {
+    if !visited.insert(project.id.clone()) {
+        return;
+    }
+
+    let (branch, child_prefix) = match is_last {
+        None => (String::new(), String::new()),
+        Some(true) => (format!("{}└── ", prefix), format!("{}    ", prefix)),
+        Some(false) => (format!("{}├── ", prefix), format!("{}│   ", prefix)),
+    };
+
+    println!("{}{} {} {} {}",
+        branch.bright_black(),
+        get_type_emoji(&project.project_type),
+        project.name.bright_white(),
+        format_status(&project.status),
+        format!("{:.0}%", crate::core::rollup_progress(project, all_projects)).bright_green()
+    );
+
+    let children: Vec<&Project> = projects.iter()
+        .filter(|p| p.parent.as_deref() == Some(project.id.as_str()))
+        .collect();
+
+    for (i, child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        print_tree_node(child, projects, all_projects, &child_prefix, Some(last), visited);
+    }
+}
+
 async fn print_json(projects: &[Project]) -> Result<()> {
     let json = serde_json::to_string_pretty(projects)?;
     println!("{}", json);
@@ -136,6 +239,20 @@ async fn print_yaml(projects: &[Project]) -> Result<()> {
     Ok(())
 }
 
+/// Table cell for a column, styled like the default view.
+fn format_cell(project: &Project, column: &Column) -> String {
+    match column {
+        Column::Name => format!("{} {}", get_type_emoji(&project.project_type), truncate_string(&project.name, NAME_WIDTH)),
+        Column::Type => format_project_type(&project.project_type),
+        Column::Priority => format_priority(&project.priority),
+        Column::Status => format_status(&project.status),
+        Column::Updated => format_relative_time(&project.updated),
+        Column::Created => project.created.format("%Y-%m-%d").to_string(),
+        Column::Progress => format!("{:.0}%", project.get_progress_percentage()),
+        column => cell_text(&column_value(project, column)),
+    }
+}
+
 fn count_by_status(projects: &[Project]) -> std::collections::HashMap<String, usize> {
     let mut counts = std::collections::HashMap::new();
     for project in projects {
@@ -1,6 +1,8 @@
 use anyhow::Result;
+use crate::utils::output::require_text_output;
 
 pub async fn execute(_source: String) -> Result<()> {
+    require_text_output("migrate")?;
     println!("🚧 Migration features coming soon!");
     println!("This will help migrate existing projects to the new structure.");
     Ok(())
@@ -1,8 +1,26 @@
 pub mod new;
 pub mod list;
 pub mod status;
+pub mod archive;
 pub mod info;
 pub mod template;
 pub mod migrate;
 pub mod config;
 pub mod stats;
+pub mod tasks;
+pub mod time;
+pub mod link;
+pub mod graph;
+pub mod tui;
+pub mod board;
+pub mod kanban;
+pub mod dashboard;
+pub mod watch;
+pub mod attention;
+pub mod doctor;
+pub mod cache;
+pub mod backup;
+pub mod history;
+pub mod report;
+pub mod search;
+pub mod query;
@@ -4,11 +4,18 @@ use dialoguer::{Input, Select};
 use chrono::Utc;
 
 use crate::cli::{ProjectType, Priority};
-use crate::core::{Project, ProjectStatus};
+use crate::cli::commands::link;
+use crate::core::{self, Project, ProjectStatus};
 use crate::config::Settings;
 use crate::fs::operations;
+use crate::fs::scanner::{self, ProjectScanner};
 use crate::utils::template::TemplateProcessor;
+use crate::utils::date::parse_date_string;
+use crate::utils::error;
+use crate::utils::export::project_object;
+use crate::utils::output::{emit_json, json_output, require_text_output};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     name: Option<String>,
     project_type: Option<ProjectType>,
@@ -17,12 +24,41 @@ pub async fn execute(
     template: Option<String>,
     no_git: bool,
     interactive: bool,
+    parent: Option<String>,
+    due: Option<String>,
 ) -> Result<()> {
-    println!("{}", "✨ Create a new project".bright_cyan().bold());
-    println!();
+    let json = json_output();
+    let due = due
+        .map(|value| parse_date_string(&value)
+            .map(|date| date.date_naive())
+            .map_err(|_| error::invalid_argument(format!("Invalid due date '{}'. Use YYYY-MM-DD", value))))
+        .transpose()?;
+    if interactive {
+        require_text_output("new --interactive")?;
+    }
+    if json && name.is_none() {
+        return Err(error::invalid_argument("Project name is required"));
+    }
+
+    if !json {
+        println!("{}", "✨ Create a new project".bright_cyan().bold());
+        println!();
+    }
 
     let settings = Settings::load().await?;
 
+    // Resolve the parent project up front so a typo fails before anything is created
+    let mut projects = Vec::new();
+    let parent_id = match parent {
+        Some(parent_name) => {
+            projects = ProjectScanner::from_settings(&settings)?.scan_projects().await?;
+            let parent_project = scanner::find_by_name(&projects, &parent_name)
+                .ok_or_else(|| error::not_found(format!("Parent project '{}' not found", parent_name)))?;
+            Some(parent_project.id.clone())
+        }
+        None => None,
+    };
+
     // Interactive mode or collect missing information
     let project_name = if let Some(name) = name {
         name
@@ -31,7 +67,7 @@ pub async fn execute(
             .with_prompt("Project name")
             .interact_text()?
     } else {
-        return Err(anyhow::anyhow!("Project name is required"));
+        return Err(error::invalid_argument("Project name is required"));
     };
 
     let project_type = if let Some(pt) = project_type {
@@ -88,15 +124,19 @@ pub async fn execute(
     };
 
     // Create project structure
-    let project = Project::new(
+    let mut project = Project::new(
         project_name.clone(),
         project_type.clone(),
         priority.clone(),
         description,
     );
+    project.parent = parent_id;
+    project.due = due;
 
-    println!();
-    println!("{} Creating project \"{}\"...", "🚀".bright_green(), project_name.bright_white().bold());
+    if !json {
+        println!();
+        println!("{} Creating project \"{}\"...", "🚀".bright_green(), project_name.bright_white().bold());
+    }
 
     // Generate project directory name
     let today = Utc::now().format("%Y-%m-%d").to_string();
@@ -111,76 +151,128 @@ pub async fn execute(
     // Create project directory
     let project_path = settings.projects_path().join(&dir_name);
     
-    println!("{} Creating directory: {}", 
-        "📁".bright_blue(), 
-        project_path.display().to_string().bright_yellow()
-    );
+    if !json {
+        println!("{} Creating directory: {}",
+            "📁".bright_blue(),
+            project_path.display().to_string().bright_yellow()
+        );
+    }
 
-    if !project_path.exists() {
+    if project_path.exists() {
+        // Someone else's notes or metadata may live there
+        if !core::force() {
+            return Err(error::conflict(format!(
+                "{} already exists (use --force to create the project in it anyway)",
+                project_path.display()
+            )));
+        }
+    } else {
         std::fs::create_dir_all(&project_path)
             .context("Failed to create project directory")?;
+        // Undoing `new` removes the folder with everything written into it
+        crate::fs::history::record_create_dir(&project_path)?;
     }
 
     // Copy template
     let template_name = template.unwrap_or_else(|| {
-        match project_type {
-            ProjectType::WebApp => "Web-App-Project".to_string(),
-            ProjectType::Tool => "Tool-Project".to_string(),
-            ProjectType::Content => "Content-Project".to_string(),
-            ProjectType::Api => "API-Project".to_string(),
-        }
+        core::template_name(&project.project_type).unwrap_or_default().to_string()
     });
 
     let template_path = settings.templates_path().join(&template_name);
     
-    println!("{} Copying template: {} -> {}", 
-        "📄".bright_blue(), 
-        template_name.bright_cyan(), 
-        project_name.bright_white()
-    );
+    if !json {
+        println!("{} Copying template: {} -> {}",
+            "📄".bright_blue(),
+            template_name.bright_cyan(),
+            project_name.bright_white()
+        );
+    }
 
-    if template_path.exists() {
+    let template_found = template_path.exists();
+    if template_found {
         operations::copy_dir_recursive(&template_path, &project_path).await
             .context("Failed to copy template")?;
     } else {
-        println!("{} Template not found, creating basic structure", "⚠️".bright_yellow());
+        if !json {
+            println!("{} Template not found, creating basic structure", "⚠️".bright_yellow());
+        }
         create_basic_structure(&project_path, &project).await?;
     }
 
     // Process template variables
-    println!("{} Updating project metadata...", "✏️".bright_blue());
+    if !json {
+        println!("{} Updating project metadata...", "✏️".bright_blue());
+    }
     
     let processor = TemplateProcessor::new(&project);
     processor.process_directory(&project_path).await
         .context("Failed to process template variables")?;
 
-    // Create metadata file
-    let metadata_path = project_path.join(".project-meta.yaml");
-    let metadata_content = serde_yaml::to_string(&project)
-        .context("Failed to serialize project metadata")?;
-    
-    tokio::fs::write(&metadata_path, metadata_content).await
+    // A parent without metadata gets a new ID on every scan; saving it keeps
+    // the reference from dangling
+    if let Some(parent_id) = &project.parent {
+        if let Some(parent_project) = projects.iter_mut().find(|p| &p.id == parent_id) {
+            if let Some(dir) = parent_project.path.clone().filter(|dir| !dir.join(core::METADATA_FILE).exists()) {
+                core::save_project_metadata(parent_project, &dir).await
+                    .context("Failed to write parent project metadata")?;
+            }
+        }
+    }
+
+    // Create metadata file. A metadata file already there is only replaced
+    // with --force.
+    core::save_project_metadata(&mut project, &project_path).await
         .context("Failed to write project metadata")?;
 
+    // Link the new sub-project and its parent in both overview notes
+    if let Some(parent_id) = &project.parent {
+        project.path = Some(project_path.clone());
+        projects.push(project.clone());
+
+        if let Some(parent_project) = core::find_by_id(&projects, parent_id) {
+            if !json {
+                println!("{} Linking to parent project \"{}\"",
+                    "🔗".bright_blue(),
+                    parent_project.name.bright_white()
+                );
+            }
+            link::write_relations_note(parent_project, &projects, &settings.obsidian_path).await?;
+        }
+        link::write_relations_note(&project, &projects, &settings.obsidian_path).await?;
+    }
+
     // Initialize Git repository if requested
+    let mut git_initialized = false;
     if !no_git && settings.auto_git_init {
-        println!("{} Initializing Git repository...", "📦".bright_blue());
-        
+        if !json {
+            println!("{} Initializing Git repository...", "📦".bright_blue());
+        }
+
         let output = std::process::Command::new("git")
             .args(&["init"])
             .current_dir(&project_path)
             .output();
 
-        match output {
-            Ok(output) if output.status.success() => {
+        git_initialized = matches!(output, Ok(ref output) if output.status.success());
+        if !json {
+            if git_initialized {
                 println!("{} Git repository initialized", "✅".bright_green());
-            }
-            _ => {
+            } else {
                 println!("{} Failed to initialize Git repository", "⚠️".bright_yellow());
             }
         }
     }
 
+    if json {
+        project.path = Some(project_path.clone());
+        return emit_json(serde_json::json!({
+            "project": project_object(&project),
+            "template": template_name,
+            "template_found": template_found,
+            "git_initialized": git_initialized,
+        }));
+    }
+
     // Success message
     println!();
     println!("{} Project \"{}\" created successfully!", 
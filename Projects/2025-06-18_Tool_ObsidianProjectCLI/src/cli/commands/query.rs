@@ -0,0 +1,73 @@
+use anyhow::Result;
+use colored::*;
+use crate::cli::QueryCommand;
+use crate::config::Settings;
+use crate::core::ProjectQuery;
+use crate::utils::error;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(subcommand: QueryCommand) -> Result<()> {
+    let mut settings = Settings::load().await?;
+
+    match subcommand {
+        QueryCommand::List => {
+            if json_output() {
+                return emit_json(serde_json::json!({ "queries": settings.saved_queries }));
+            }
+
+            if settings.saved_queries.is_empty() {
+                println!("{}", "No saved queries.".bright_yellow());
+                println!("{} Use {} to add one",
+                    "💡".bright_blue(),
+                    "'opj query save <name> <query>'".bright_cyan()
+                );
+                return Ok(());
+            }
+
+            println!("{}", "🔎 Saved Queries".bright_cyan().bold());
+            for (name, query) in &settings.saved_queries {
+                println!("  {} {}", format!("@{}", name).bright_white().bold(), query.bright_black());
+            }
+        }
+        QueryCommand::Save { name, query } => {
+            let name = name.trim_start_matches('@').to_string();
+            if name.is_empty() || name.contains(char::is_whitespace) {
+                return Err(error::invalid_argument("Query names must be non-empty and contain no spaces"));
+            }
+
+            let query = query.join(" ");
+            let mut saved = settings.saved_queries.clone();
+            saved.insert(name.clone(), query.clone());
+            ProjectQuery::parse(&query, &saved)?;
+
+            settings.saved_queries = saved;
+            settings.save().await?;
+
+            if json_output() {
+                return emit_json(serde_json::json!({ "name": name, "query": query }));
+            }
+            println!("{} Saved query {}: {}",
+                "✅".bright_green(),
+                format!("@{}", name).bright_white().bold(),
+                query.bright_cyan()
+            );
+        }
+        QueryCommand::Remove { name } => {
+            let name = name.trim_start_matches('@');
+            let Some(query) = settings.saved_queries.remove(name) else {
+                return Err(error::not_found(format!("No saved query named '@{}'", name)));
+            };
+            settings.save().await?;
+
+            if json_output() {
+                return emit_json(serde_json::json!({ "name": name, "query": query }));
+            }
+            println!("{} Removed saved query {}",
+                "🗑️".bright_red(),
+                format!("@{}", name).bright_white().bold()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use colored::*;
+use std::path::PathBuf;
+use crate::cli::{ReportFormat, ReportPeriod};
+use crate::config::Settings;
+use crate::core::{self, DateRange};
+use crate::fs::operations::write_string_to_file;
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::date::parse_date_string;
+use crate::utils::error;
+use crate::utils::export::{project_ref, timestamp};
+use crate::utils::output::{emit_json, json_output};
+
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+    period: ReportPeriod,
+    from: Option<String>,
+    to: Option<String>,
+    format: ReportFormat,
+    output: Option<String>,
+    note: Option<String>,
+    query: Option<String>,
+) -> Result<()> {
+    let settings = Settings::load().await?;
+    let query = settings.parse_query(query.as_deref())?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let mut projects = scanner.scan_projects().await?;
+    query.apply(&mut projects);
+
+    let range = resolve_range(period, from, to)?;
+    let report = core::build_report(&projects, range);
+
+    let markdown = core::render_report_markdown(&report, &settings.obsidian_path);
+    let rendered = match format {
+        ReportFormat::Md => markdown.clone(),
+        ReportFormat::Html => core::render_report_html(&report),
+    };
+
+    let to_note = note.is_some();
+    let mut written = Vec::new();
+    if let Some(note) = note {
+        // Vault notes are always Markdown so that links resolve in Obsidian
+        let note_path = if note.is_empty() {
+            settings.projects_path()
+                .join("_Reports")
+                .join(format!("{}_{}.md", range.start.format("%Y-%m-%d"), range.end.format("%Y-%m-%d")))
+        } else {
+            let mut path = settings.obsidian_path.join(&note);
+            if path.extension().is_none() {
+                path.set_extension("md");
+            }
+            path
+        };
+
+        write_string_to_file(&note_path, &markdown).await?;
+        written.push(note_path.display().to_string());
+        if !json_output() {
+            println!("{} Wrote progress report for {} to {}",
+                "📈".bright_blue(),
+                range,
+                note_path.display().to_string().bright_cyan()
+            );
+        }
+    }
+
+    if let Some(output) = output {
+        let output_path = PathBuf::from(&output);
+        write_string_to_file(&output_path, &rendered).await?;
+        written.push(output_path.display().to_string());
+        if !json_output() {
+            println!("{} Wrote progress report for {} to {}",
+                "📈".bright_blue(),
+                range,
+                output_path.display().to_string().bright_cyan()
+            );
+        }
+    } else if !to_note && !json_output() {
+        print!("{}", rendered);
+    }
+
+    if json_output() {
+        return emit_json(report_json(&report, &format, &rendered, written));
+    }
+
+    Ok(())
+}
+
+fn report_json(report: &core::ProgressReport, format: &ReportFormat, rendered: &str, written: Vec<String>) -> serde_json::Value {
+    serde_json::json!({
+        "range": {
+            "start": report.range.start.format("%Y-%m-%d").to_string(),
+            "end": report.range.end.format("%Y-%m-%d").to_string(),
+        },
+        "transitions": report.transitions.iter()
+            .map(|t| serde_json::json!({
+                "project": project_ref(t.project),
+                "from": t.from.to_string(),
+                "to": t.to.to_string(),
+                "at": timestamp(&t.at),
+                "note": t.note,
+            }))
+            .collect::<Vec<_>>(),
+        "created": report.created.iter().map(|p| project_ref(p)).collect::<Vec<_>>(),
+        "completed": report.completed.iter()
+            .map(|(p, at)| serde_json::json!({ "project": project_ref(p), "at": timestamp(at) }))
+            .collect::<Vec<_>>(),
+        "stale": report.stale.iter().map(|p| project_ref(p)).collect::<Vec<_>>(),
+        "time_logged": report.time_logged.iter()
+            .map(|(p, duration)| serde_json::json!({ "project": project_ref(p), "minutes": duration.num_minutes() }))
+            .collect::<Vec<_>>(),
+        "time_logged_minutes": report.total_time.num_minutes(),
+        "format": match format {
+            ReportFormat::Md => "md",
+            ReportFormat::Html => "html",
+        },
+        "content": rendered,
+        "written_to": written,
+    })
+}
+
+fn resolve_range(period: ReportPeriod, from: Option<String>, to: Option<String>) -> Result<DateRange> {
+    let today = Utc::now().date_naive();
+
+    match period {
+        ReportPeriod::Custom => {
+            let from = from
+                .ok_or_else(|| error::invalid_argument("--period custom requires --from"))?;
+            let end = match to {
+                Some(to) => parse_day(&to)?,
+                None => today,
+            };
+            DateRange::new(parse_day(&from)?, end)
+        }
+        _ if from.is_some() || to.is_some() => Err(error::invalid_argument(
+            "--from and --to can only be used with --period custom"
+        )),
+        ReportPeriod::Week => Ok(DateRange::week_to_date(today)),
+        ReportPeriod::Month => Ok(DateRange::month_to_date(today)),
+    }
+}
+
+fn parse_day(value: &str) -> Result<NaiveDate> {
+    parse_date_string(value)
+        .map(|date| date.date_naive())
+        .map_err(|_| error::invalid_argument(format!("Invalid date '{}'. Use YYYY-MM-DD", value)))
+}
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use crate::cli::{Column, OutputFormat, SortField};
+
+/// `opj search` is `opj list --query` with the query as positional words.
+pub async fn execute(query: String, format: OutputFormat, columns: Vec<Column>, strict: bool, include_archived: bool) -> Result<()> {
+    crate::cli::commands::list::execute(
+        None,
+        None,
+        None,
+        Some(query),
+        SortField::Updated,
+        format,
+        columns,
+        None,
+        false,
+        strict,
+        include_archived,
+    ).await
+}
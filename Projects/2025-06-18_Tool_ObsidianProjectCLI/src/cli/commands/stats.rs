@@ -1,42 +1,79 @@
 use anyhow::Result;
 use colored::*;
+use crate::cli::{OutputFormat, TrendInterval};
 use crate::config::Settings;
+use crate::core::{self, ProjectStats, Trends};
 use crate::fs::scanner::ProjectScanner;
+use crate::utils::export::{render_records, stats_object, trends_object, Records};
+use crate::utils::output::{emit_json_with_warnings, json_output, print_scan_warnings, sparkline};
+use crate::utils::date::format_duration_hm;
 
-pub async fn execute() -> Result<()> {
+pub async fn execute(
+    format: OutputFormat,
+    query: Option<String>,
+    trends: bool,
+    interval: TrendInterval,
+    last: usize,
+    strict: bool,
+    include_archived: bool,
+) -> Result<()> {
     let settings = Settings::load().await?;
-    let scanner = ProjectScanner::new(settings.projects_path());
-    let projects = scanner.scan_projects().await?;
+    let query = settings.parse_query(query.as_deref())?;
+    let scanner = ProjectScanner::from_settings(&settings)?.include_archived(include_archived);
+    let scan = scanner.scan().await?;
+    scan.ensure_complete(strict)?;
+    let mut projects = scan.projects;
+    query.apply(&mut projects);
+
+    if trends {
+        let trends = core::compute_trends(&projects, interval.into(), last, chrono::Utc::now());
+        if json_output() {
+            return emit_json_with_warnings(trends_object(&trends), &scan.warnings);
+        }
+        if matches!(format, OutputFormat::Table) {
+            print_trends(&trends)?;
+        } else {
+            print!("{}", render_records(&Records::from_trends(&trends), &format)?);
+        }
+        print_scan_warnings(&scan.warnings);
+        return Ok(());
+    }
+
+    let stats = ProjectStats::compute(&projects);
+
+    if json_output() {
+        return emit_json_with_warnings(stats_object(&stats), &scan.warnings);
+    }
+
+    if !matches!(format, OutputFormat::Table) {
+        print!("{}", render_records(&Records::from_stats(&stats), &format)?);
+        print_scan_warnings(&scan.warnings);
+        return Ok(());
+    }
 
     println!("{}", "📊 Project Statistics".bright_cyan().bold());
     println!();
 
     // Overall statistics
-    print_overall_stats(&projects)?;
-    print_status_breakdown(&projects)?;
-    print_type_breakdown(&projects)?;
-    print_priority_breakdown(&projects)?;
-    print_activity_stats(&projects)?;
+    print_overall_stats(&stats)?;
+    print_status_breakdown(&stats)?;
+    print_type_breakdown(&stats)?;
+    print_priority_breakdown(&stats)?;
+    print_activity_stats(&projects, &stats)?;
+    print_time_stats(&projects)?;
 
+    print_scan_warnings(&scan.warnings);
     Ok(())
 }
 
-fn print_overall_stats(projects: &[crate::core::Project]) -> Result<()> {
-    let total_projects = projects.len();
-    let active_projects = projects.iter()
-        .filter(|p| p.is_active())
-        .count();
-    let completed_projects = projects.iter()
-        .filter(|p| matches!(p.status, crate::core::ProjectStatus::Completed))
-        .count();
-
+fn print_overall_stats(stats: &ProjectStats) -> Result<()> {
     println!("{}", "📈 Overall".bright_blue().bold());
-    println!("  Total Projects: {}", total_projects.to_string().bright_white().bold());
-    println!("  Active Projects: {}", active_projects.to_string().bright_green());
-    println!("  Completed Projects: {}", completed_projects.to_string().bright_cyan());
+    println!("  Total Projects: {}", stats.total.to_string().bright_white().bold());
+    println!("  Active Projects: {}", stats.active.to_string().bright_green());
+    println!("  Completed Projects: {}", stats.completed.to_string().bright_cyan());
     println!("  Completion Rate: {}%", 
-        if total_projects > 0 {
-            format!("{:.1}", (completed_projects as f32 / total_projects as f32) * 100.0).bright_yellow()
+        if stats.total > 0 {
+            format!("{:.1}", stats.completion_rate()).bright_yellow()
         } else {
             "0".bright_yellow()
         }
@@ -46,63 +83,32 @@ fn print_overall_stats(projects: &[crate::core::Project]) -> Result<()> {
     Ok(())
 }
 
-fn print_status_breakdown(projects: &[crate::core::Project]) -> Result<()> {
+fn print_status_breakdown(stats: &ProjectStats) -> Result<()> {
     println!("{}", "📋 Status Breakdown".bright_blue().bold());
 
-    let statuses = [
-        ("Idea", crate::core::ProjectStatus::Idea),
-        ("Planning", crate::core::ProjectStatus::Planning),
-        ("Development", crate::core::ProjectStatus::Development),
-        ("Testing", crate::core::ProjectStatus::Testing),
-        ("Completed", crate::core::ProjectStatus::Completed),
-        ("Archived", crate::core::ProjectStatus::Archived),
-        ("Cancelled", crate::core::ProjectStatus::Cancelled),
-    ];
-
-    for (name, status) in &statuses {
-        let count = projects.iter()
-            .filter(|p| std::mem::discriminant(&p.status) == std::mem::discriminant(status))
-            .count();
+    for (status, count) in &stats.by_status {
+        let emoji = match status {
+            crate::core::ProjectStatus::Idea => "💡",
+            crate::core::ProjectStatus::Planning => "📋",
+            crate::core::ProjectStatus::Development => "🚧",
+            crate::core::ProjectStatus::Testing => "🧪",
+            crate::core::ProjectStatus::Completed => "✅",
+            crate::core::ProjectStatus::Archived => "📦",
+            crate::core::ProjectStatus::Cancelled => "❌",
+        };
         
-        if count > 0 {
-            let emoji = match status {
-                crate::core::ProjectStatus::Idea => "💡",
-                crate::core::ProjectStatus::Planning => "📋",
-                crate::core::ProjectStatus::Development => "🚧",
-                crate::core::ProjectStatus::Testing => "🧪",
-                crate::core::ProjectStatus::Completed => "✅",
-                crate::core::ProjectStatus::Archived => "📦",
-                crate::core::ProjectStatus::Cancelled => "❌",
-            };
-            
-            println!("  {} {}: {}", emoji, name, count.to_string().bright_white());
-        }
+        println!("  {} {}: {}", emoji, status, count.to_string().bright_white());
     }
     println!();
 
     Ok(())
 }
 
-fn print_type_breakdown(projects: &[crate::core::Project]) -> Result<()> {
+fn print_type_breakdown(stats: &ProjectStats) -> Result<()> {
     println!("{}", "🏷️ Type Breakdown".bright_blue().bold());
 
-    let mut type_counts = std::collections::HashMap::new();
-    for project in projects {
-        let type_name = match &project.project_type {
-            crate::core::ProjectType::WebApp => "Web-App",
-            crate::core::ProjectType::Tool => "Tool",
-            crate::core::ProjectType::Content => "Content",
-            crate::core::ProjectType::Api => "API",
-            crate::core::ProjectType::Custom(name) => name.as_str(),
-        };
-        *type_counts.entry(type_name).or_insert(0) += 1;
-    }
-
-    let mut sorted_types: Vec<_> = type_counts.iter().collect();
-    sorted_types.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
-
-    for (type_name, count) in sorted_types {
-        let emoji = match *type_name {
+    for (type_name, count) in &stats.by_type {
+        let emoji = match type_name.as_str() {
             "Web-App" => "🌐",
             "Tool" => "🛠️",
             "Content" => "📝",
@@ -116,30 +122,23 @@ fn print_type_breakdown(projects: &[crate::core::Project]) -> Result<()> {
     Ok(())
 }
 
-fn print_priority_breakdown(projects: &[crate::core::Project]) -> Result<()> {
+fn print_priority_breakdown(stats: &ProjectStats) -> Result<()> {
     println!("{}", "🎯 Priority Breakdown".bright_blue().bold());
 
-    let priorities = [
-        ("High", crate::core::Priority::High, "🔴"),
-        ("Medium", crate::core::Priority::Medium, "🟡"),
-        ("Low", crate::core::Priority::Low, "🔵"),
-    ];
-
-    for (name, priority, emoji) in &priorities {
-        let count = projects.iter()
-            .filter(|p| std::mem::discriminant(&p.priority) == std::mem::discriminant(priority))
-            .count();
-        
-        if count > 0 {
-            println!("  {} {}: {}", emoji, name, count.to_string().bright_white());
-        }
+    for (priority, count) in &stats.by_priority {
+        let emoji = match priority {
+            crate::core::Priority::High => "🔴",
+            crate::core::Priority::Medium => "🟡",
+            crate::core::Priority::Low => "🔵",
+        };
+        println!("  {} {}: {}", emoji, priority, count.to_string().bright_white());
     }
     println!();
 
     Ok(())
 }
 
-fn print_activity_stats(projects: &[crate::core::Project]) -> Result<()> {
+fn print_activity_stats(projects: &[crate::core::Project], stats: &ProjectStats) -> Result<()> {
     if projects.is_empty() {
         return Ok(());
     }
@@ -171,23 +170,15 @@ fn print_activity_stats(projects: &[crate::core::Project]) -> Result<()> {
     }
 
     // Average project age
-    let total_age: i64 = projects.iter()
-        .map(|p| p.days_since_creation())
-        .sum();
-    let avg_age = total_age as f32 / projects.len() as f32;
-    
     println!("  Average Project Age: {:.1} days", 
-        avg_age.to_string().bright_cyan()
+        stats.average_age_days.to_string().bright_cyan()
     );
 
     // Active projects needing attention (not updated in a week)
-    let stale_projects = projects.iter()
-        .filter(|p| p.is_active() && p.days_since_update() > 7)
-        .count();
-    
-    if stale_projects > 0 {
-        println!("  {} Projects Need Attention (>7 days inactive)", 
-            stale_projects.to_string().bright_red()
+    if stats.stale > 0 {
+        println!("  {} Projects Need Attention (>{} days inactive)", 
+            stats.stale.to_string().bright_red(),
+            crate::core::STALE_AFTER_DAYS
         );
     }
 
@@ -195,3 +186,159 @@ fn print_activity_stats(projects: &[crate::core::Project]) -> Result<()> {
 
     Ok(())
 }
+
+fn print_time_stats(projects: &[crate::core::Project]) -> Result<()> {
+    let entries: Vec<&crate::core::TimeEntry> = projects.iter()
+        .flat_map(|p| p.time_entries.iter())
+        .collect();
+
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    println!("{}", "⏱️ Time Tracking".bright_blue().bold());
+
+    let total = entries.iter()
+        .fold(chrono::Duration::zero(), |total, entry| total + entry.duration());
+    println!("  Total Logged: {}", format_duration_hm(&total).bright_white().bold());
+
+    // Most tracked projects
+    let mut tracked: Vec<_> = projects.iter()
+        .filter(|p| !p.time_entries.is_empty())
+        .map(|p| (p, p.total_time_logged()))
+        .collect();
+    tracked.sort_by_key(|(_, duration)| std::cmp::Reverse(*duration));
+
+    for (project, duration) in tracked.iter().take(5) {
+        println!("  • {}: {}", project.name, format_duration_hm(duration).bright_cyan());
+    }
+
+    // Per-week breakdown
+    println!();
+    println!("  {}", "Per Week".bright_white());
+    let weekly = crate::core::weekly_totals(entries, 4, chrono::Utc::now());
+    let max_minutes = weekly.iter()
+        .map(|(_, duration)| duration.num_minutes())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    for (week, duration) in &weekly {
+        let bar_width = (duration.num_minutes() * 20 / max_minutes) as usize;
+        println!("  {} {:<20} {}",
+            week.format("%G-W%V").to_string().bright_black(),
+            "█".repeat(bar_width).bright_green(),
+            format_duration_hm(duration).bright_cyan()
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+fn print_trends(trends: &Trends) -> Result<()> {
+    let unit = match trends.interval {
+        core::Interval::Week => "week",
+        core::Interval::Month => "month",
+    };
+
+    println!("{} (last {} {}s)",
+        "📈 Project Trends".bright_cyan().bold(),
+        trends.buckets.len(),
+        unit
+    );
+    println!();
+
+    // Sparklines, oldest to newest
+    let series = |values: Vec<usize>| -> (String, usize) {
+        let total = values.iter().sum();
+        (sparkline(&values.iter().map(|v| *v as f64).collect::<Vec<_>>()), total)
+    };
+    let (completed, completed_total) = series(trends.buckets.iter().map(|b| b.completed).collect());
+    let (started, started_total) = series(trends.buckets.iter().map(|b| b.started).collect());
+    let (wip, _) = series(trends.buckets.iter().map(|b| b.wip).collect());
+
+    println!("  {:<11} {}  {} total, {:.1}/{}",
+        "Completed",
+        completed.bright_green(),
+        completed_total.to_string().bright_white(),
+        completed_total as f64 / trends.buckets.len().max(1) as f64,
+        unit
+    );
+    println!("  {:<11} {}  {} total",
+        "Started",
+        started.bright_cyan(),
+        started_total.to_string().bright_white()
+    );
+    println!("  {:<11} {}  {} now",
+        "In progress",
+        wip.bright_yellow(),
+        trends.buckets.last().map(|b| b.wip).unwrap_or(0).to_string().bright_white()
+    );
+    println!();
+
+    // Throughput per bucket
+    println!("{}", "✅ Throughput".bright_blue().bold());
+    let max_completed = trends.buckets.iter().map(|b| b.completed).max().unwrap_or(0).max(1);
+    for bucket in &trends.buckets {
+        println!("  {:<8} {:<20} {} done, {} in progress",
+            trends.interval.label(bucket.start).bright_black(),
+            "█".repeat(bucket.completed * 20 / max_completed).bright_green(),
+            bucket.completed.to_string().bright_white(),
+            bucket.wip
+        );
+    }
+    println!();
+
+    // Cycle time
+    println!("{}", "⏳ Cycle Time (Development → Completed)".bright_blue().bold());
+    match (trends.average_cycle_time(), trends.median_cycle_time()) {
+        (Some(average), Some(median)) => {
+            println!("  {} projects · average {} · median {}",
+                trends.cycle_times.len().to_string().bright_white(),
+                format_days(average).bright_cyan(),
+                format_days(median).bright_cyan()
+            );
+            for cycle in trends.cycle_times.iter().take(5) {
+                println!("  • {}: {} ({} → {})",
+                    cycle.project.name,
+                    format_days(cycle.duration()).bright_cyan(),
+                    cycle.started.format("%Y-%m-%d"),
+                    cycle.completed.format("%Y-%m-%d")
+                );
+            }
+        }
+        _ => println!("  {}", "No project has gone from Development to Completed yet.".bright_yellow()),
+    }
+    println!();
+
+    // Time in status
+    println!("{}", "🕒 Time in Status".bright_blue().bold());
+    let max_average = trends.time_in_status.iter()
+        .map(|time| time.average().num_minutes())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for time in &trends.time_in_status {
+        let bar_width = (time.average().num_minutes() * 20 / max_average) as usize;
+        println!("  {:<12} {:<20} {} average ({} {})",
+            time.status.to_string(),
+            "█".repeat(bar_width).bright_green(),
+            format_days(time.average()).bright_cyan(),
+            time.projects,
+            if time.projects == 1 { "project" } else { "projects" }
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+fn format_days(duration: chrono::Duration) -> String {
+    let days = duration.num_minutes() as f64 / (60.0 * 24.0);
+    if days < 1.0 {
+        format_duration_hm(&duration)
+    } else {
+        format!("{:.1} days", days)
+    }
+}
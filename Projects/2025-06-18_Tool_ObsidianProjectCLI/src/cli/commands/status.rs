@@ -2,7 +2,11 @@ use anyhow::Result;
 use colored::*;
 use crate::cli::ProjectStatus;
 use crate::config::Settings;
-use crate::fs::scanner::ProjectScanner;
+use crate::core::{self, Project};
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::utils::error;
+use crate::utils::export::{project_object, project_ref, status_history};
+use crate::utils::output::{emit_json, json_output};
 
 pub async fn execute(
     project_name: String,
@@ -11,13 +15,22 @@ pub async fn execute(
     log: bool,
 ) -> Result<()> {
     let settings = Settings::load().await?;
-    let scanner = ProjectScanner::new(settings.projects_path());
+    let scanner = ProjectScanner::from_settings(&settings)?;
     
     // Find the project
-    let mut project = scanner.find_project_by_name(&project_name).await?
-        .ok_or_else(|| anyhow::anyhow!("Project '{}' not found", project_name))?;
+    let projects = scanner.scan_projects().await?;
+    let mut project = scanner::find_by_name(&projects, &project_name)
+        .cloned()
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", project_name)))?;
 
     if log {
+        if json_output() {
+            return emit_json(serde_json::json!({
+                "project": project_ref(&project),
+                "status_history": status_history(&project),
+            }));
+        }
+
         // Show status history
         print_status_history(&project)?;
         return Ok(());
@@ -26,17 +39,28 @@ pub async fn execute(
     if let Some(new_status) = set_status {
         // Update status
         let old_status = project.status.clone();
-        let new_core_status = new_status.into();
-        
-        project.update_status(new_core_status, note.clone());
-        
-        // Save updated metadata
-        if let Some(project_path) = &project.path {
-            let metadata_path = project_path.join(".project-meta.yaml");
-            let metadata_content = serde_yaml::to_string(&project)?;
-            tokio::fs::write(&metadata_path, metadata_content).await?;
+        let new_core_status: core::ProjectStatus = new_status.into();
+
+        if json_output() {
+            let blocked_by: Vec<serde_json::Value> = blocked_by(&project, &new_core_status, &projects)
+                .into_iter()
+                .map(project_ref)
+                .collect();
+            apply_status_change(&mut project, new_core_status, note.clone()).await?;
+
+            return emit_json(serde_json::json!({
+                "project": project_object(&project),
+                "from": old_status.to_string(),
+                "to": project.status.to_string(),
+                "note": note,
+                "blocked_by": blocked_by,
+            }));
         }
 
+        warn_blocking_dependencies(&project, &new_core_status, &projects);
+
+        apply_status_change(&mut project, new_core_status, note.clone()).await?;
+
         // Show update confirmation
         println!("{} Updating project status...", "🔄".bright_blue());
         println!();
@@ -59,6 +83,8 @@ pub async fn execute(
         // Suggest next action
         suggest_next_action(&project.status)?;
         
+    } else if json_output() {
+        emit_json(serde_json::json!({ "project": project_object(&project) }))?;
     } else {
         // Show current status
         print_current_status(&project)?;
@@ -67,6 +93,50 @@ pub async fn execute(
     Ok(())
 }
 
+/// Update a project's status and persist its metadata. This is the single
+/// write path for status changes.
+pub async fn apply_status_change(
+    project: &mut Project,
+    new_status: core::ProjectStatus,
+    note: Option<String>,
+) -> Result<()> {
+    project.update_status(new_status, note);
+
+    if let Some(project_path) = project.path.clone() {
+        core::save_project_metadata(project, &project_path).await?;
+    }
+
+    Ok(())
+}
+
+/// Unfinished dependencies, when moving into a status that needs them done.
+fn blocked_by<'a>(project: &Project, new_status: &core::ProjectStatus, projects: &'a [Project]) -> Vec<&'a Project> {
+    if !matches!(
+        new_status,
+        core::ProjectStatus::Development | core::ProjectStatus::Testing | core::ProjectStatus::Completed
+    ) {
+        return Vec::new();
+    }
+
+    core::blocking_dependencies(project, projects)
+}
+
+fn warn_blocking_dependencies(project: &Project, new_status: &core::ProjectStatus, projects: &[Project]) {
+    let blocking = blocked_by(project, new_status, projects);
+    if blocking.is_empty() {
+        return;
+    }
+
+    println!("{} \"{}\" is blocked by unfinished dependencies:",
+        "⚠️".bright_yellow(),
+        project.name.bright_white().bold()
+    );
+    for dep in blocking {
+        println!("  • {} ({})", dep.name.bright_white(), format_status_colored(&dep.status));
+    }
+    println!();
+}
+
 fn print_status_history(project: &crate::core::Project) -> Result<()> {
     println!("{} Status History for \"{}\"", 
         "📈".bright_blue(), 
@@ -115,6 +185,9 @@ fn print_current_status(project: &crate::core::Project) -> Result<()> {
     println!("Updated: {}", 
         project.updated.format("%Y-%m-%d").to_string().bright_black()
     );
+    if let Some(due) = project.due {
+        println!("Due: {}", due.format("%Y-%m-%d").to_string().bright_yellow());
+    }
     
     if let Some(desc) = &project.description {
         println!();
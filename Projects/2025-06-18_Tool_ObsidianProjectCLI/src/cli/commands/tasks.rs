@@ -0,0 +1,203 @@
+use anyhow::{Result, Context};
+use colored::*;
+use std::path::{Component, Path};
+use crate::cli::TasksCommand;
+use crate::config::Settings;
+use crate::core::{self, Project, Task};
+use crate::fs::operations::write_string_to_file;
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::error;
+use crate::utils::export::project_ref;
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(subcommand: TasksCommand) -> Result<()> {
+    match subcommand {
+        TasksCommand::List { project, pending } => list_tasks(project, pending).await,
+        TasksCommand::Done { project, task_id } => set_task_state(project, task_id, true).await,
+        TasksCommand::Undone { project, task_id } => set_task_state(project, task_id, false).await,
+        TasksCommand::Add { project, text, file, section } => add_task(project, text, file, section).await,
+    }
+}
+
+async fn list_tasks(project_name: String, pending: bool) -> Result<()> {
+    let project = find_project(&project_name).await?;
+    let project_path = project_path(&project)?;
+    let tasks = core::load_project_tasks(project_path).await?;
+
+    let done_count = tasks.iter().filter(|t| t.done).count();
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "total": tasks.len(),
+            "done": done_count,
+            "tasks": tasks.iter()
+                .filter(|t| !pending || !t.done)
+                .map(|t| task_json(t, project_path))
+                .collect::<Vec<_>>(),
+        }));
+    }
+
+    println!("{} Tasks for \"{}\" ({}/{} done)",
+        "☑️".bright_blue(),
+        project.name.bright_white().bold(),
+        done_count.to_string().bright_green(),
+        tasks.len().to_string().bright_white()
+    );
+    println!();
+
+    if tasks.is_empty() {
+        println!("{}", "No checkbox tasks found in project notes.".bright_yellow());
+        return Ok(());
+    }
+
+    let mut current_file = None;
+    for task in tasks.iter().filter(|t| !pending || !t.done) {
+        if current_file != Some(&task.file) {
+            println!("{}", relative_file_name(task, project_path).bright_blue().bold());
+            current_file = Some(&task.file);
+        }
+
+        let checkbox = if task.done { "[x]".bright_green() } else { "[ ]".bright_yellow() };
+        let text = if task.done { task.text.bright_black() } else { task.text.bright_white() };
+        println!("  {:>3} {} {}", format!("#{}", task.id).bright_cyan(), checkbox, text);
+    }
+
+    println!();
+    println!("{} Use {} to complete a task",
+        "💡".bright_blue(),
+        format!("'opj tasks done \"{}\" <id>'", project.name).bright_cyan()
+    );
+
+    Ok(())
+}
+
+async fn set_task_state(project_name: String, task_id: usize, done: bool) -> Result<()> {
+    let project = find_project(&project_name).await?;
+    let project_path = project_path(&project)?;
+    let tasks = core::load_project_tasks(project_path).await?;
+
+    let task = tasks.iter()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| error::not_found(format!("Task #{} not found in project '{}'", task_id, project.name)))?;
+
+    if task.done == done {
+        if json_output() {
+            return emit_json(serde_json::json!({
+                "project": project_ref(&project),
+                "task": task_json(task, project_path),
+                "changed": false,
+            }));
+        }
+        println!("{} Task #{} is already {}",
+            "ℹ️".bright_blue(),
+            task_id,
+            if done { "done" } else { "open" }
+        );
+        return Ok(());
+    }
+
+    let content = tokio::fs::read_to_string(&task.file).await
+        .with_context(|| format!("Failed to read {}", task.file.display()))?;
+    let updated = core::set_task_done(&content, task.line, done)?;
+    write_string_to_file(&task.file, &updated).await?;
+
+    if json_output() {
+        let updated_task = Task { done, ..task.clone() };
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "task": task_json(&updated_task, project_path),
+            "changed": true,
+        }));
+    }
+
+    println!("{} Task #{} marked as {}: {}",
+        "✅".bright_green(),
+        task_id,
+        if done { "done".bright_green() } else { "open".bright_yellow() },
+        task.text.bright_white()
+    );
+    println!("  {}", format!("{}:{}", relative_file_name(task, project_path), task.line).bright_black());
+
+    Ok(())
+}
+
+async fn add_task(
+    project_name: String,
+    text: String,
+    file: String,
+    section: Option<String>,
+) -> Result<()> {
+    // Only notes of the project itself can be edited
+    if Path::new(&file).components().any(|c| !matches!(c, Component::Normal(_) | Component::CurDir)) {
+        return Err(error::invalid_argument(format!(
+            "'{}' must be a path inside the project folder",
+            file
+        )));
+    }
+
+    let project = find_project(&project_name).await?;
+    let project_path = project_path(&project)?;
+    let file_path = project_path.join(&file);
+
+    if !file_path.exists() {
+        return Err(error::not_found(format!(
+            "Note '{}' does not exist in project '{}'",
+            file,
+            project.name
+        )));
+    }
+
+    let content = tokio::fs::read_to_string(&file_path).await
+        .with_context(|| format!("Failed to read {}", file_path.display()))?;
+    let updated = core::insert_task(&content, section.as_deref(), &text);
+    write_string_to_file(&file_path, &updated).await?;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "file": file,
+            "section": section,
+            "text": text,
+        }));
+    }
+
+    println!("{} Task added to {}{}",
+        "✅".bright_green(),
+        file.bright_cyan(),
+        section.map(|s| format!(" ({})", s)).unwrap_or_default().bright_black()
+    );
+    println!("  [ ] {}", text.bright_white());
+
+    Ok(())
+}
+
+async fn find_project(project_name: &str) -> Result<Project> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+
+    scanner.find_project_by_name(project_name).await?
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", project_name)))
+}
+
+fn project_path(project: &Project) -> Result<&std::path::Path> {
+    project.path.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("Project '{}' has no directory", project.name))
+}
+
+fn task_json(task: &Task, project_path: &std::path::Path) -> serde_json::Value {
+    serde_json::json!({
+        "id": task.id,
+        "file": relative_file_name(task, project_path),
+        "line": task.line,
+        "text": task.text,
+        "done": task.done,
+    })
+}
+
+fn relative_file_name(task: &Task, project_path: &std::path::Path) -> String {
+    task.file.strip_prefix(project_path)
+        .unwrap_or(&task.file)
+        .display()
+        .to_string()
+}
@@ -1,5 +1,14 @@
 use anyhow::Result;
 use crate::cli::TemplateCommand;
+use crate::utils::output::{emit_json, json_output, require_text_output};
+
+/// Built-in templates as `(name, description)`
+const TEMPLATES: [(&str, &str); 4] = [
+    ("Web-App-Project", "Web application development"),
+    ("Tool-Project", "CLI tools and libraries"),
+    ("Content-Project", "Blog posts and tutorials"),
+    ("API-Project", "Backend APIs and services"),
+];
 
 pub async fn execute(subcommand: TemplateCommand) -> Result<()> {
     match subcommand {
@@ -11,26 +20,36 @@ pub async fn execute(subcommand: TemplateCommand) -> Result<()> {
 }
 
 async fn list_templates() -> Result<()> {
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "templates": TEMPLATES.iter()
+                .map(|(name, description)| serde_json::json!({ "name": name, "description": description }))
+                .collect::<Vec<_>>(),
+        }));
+    }
+
     println!("📋 Available Templates");
     println!();
-    println!("• Web-App-Project - Web application development");
-    println!("• Tool-Project - CLI tools and libraries");
-    println!("• Content-Project - Blog posts and tutorials");
-    println!("• API-Project - Backend APIs and services");
+    for (name, description) in TEMPLATES {
+        println!("• {} - {}", name, description);
+    }
     Ok(())
 }
 
 async fn add_template(_name: String, _path: String) -> Result<()> {
+    require_text_output("template add")?;
     println!("🚧 Template management features coming soon!");
     Ok(())
 }
 
 async fn edit_template(_name: String) -> Result<()> {
+    require_text_output("template edit")?;
     println!("🚧 Template editing features coming soon!");
     Ok(())
 }
 
 async fn remove_template(_name: String) -> Result<()> {
+    require_text_output("template remove")?;
     println!("🚧 Template removal features coming soon!");
     Ok(())
 }
@@ -0,0 +1,197 @@
+use anyhow::Result;
+use colored::*;
+use crate::cli::TimeCommand;
+use crate::config::Settings;
+use crate::core::{self, Project};
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::date::{format_duration_hm, parse_duration};
+use crate::utils::error;
+use crate::utils::export::{project_ref, timestamp};
+use crate::utils::output::{emit_json, json_output};
+
+pub async fn execute(project: Option<String>, subcommand: Option<TimeCommand>) -> Result<()> {
+    match subcommand {
+        Some(TimeCommand::Add { project, duration, note }) => add_time(project, duration, note).await,
+        None => {
+            let project = project
+                .ok_or_else(|| error::invalid_argument("Project name is required"))?;
+            show_time(project).await
+        }
+    }
+}
+
+pub async fn start(project_name: String, note: Option<String>) -> Result<()> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let projects = scanner.scan_projects().await?;
+
+    if let Some(running) = projects.iter().find(|p| p.active_time_entry().is_some()) {
+        return Err(error::conflict(format!(
+            "Already tracking time for '{}'. Run 'opj stop' first.",
+            running.name
+        )));
+    }
+
+    let mut project = scanner.find_project_by_name(&project_name).await?
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", project_name)))?;
+
+    project.start_time_tracking(note)?;
+    save(&mut project).await?;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "started_at": project.active_time_entry().map(|entry| timestamp(&entry.start)),
+        }));
+    }
+
+    println!("{} Started tracking time for \"{}\" at {}",
+        "⏱️".bright_blue(),
+        project.name.bright_white().bold(),
+        chrono::Local::now().format("%H:%M").to_string().bright_cyan()
+    );
+    println!("{} Use {} when you are done",
+        "💡".bright_blue(),
+        "'opj stop'".bright_cyan()
+    );
+
+    Ok(())
+}
+
+pub async fn stop(note: Option<String>) -> Result<()> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let projects = scanner.scan_projects().await?;
+
+    let mut project = projects.into_iter()
+        .find(|p| p.active_time_entry().is_some())
+        .ok_or_else(|| error::not_found("No time tracking session is running"))?;
+
+    let duration = project.stop_time_tracking(note)
+        .ok_or_else(|| error::not_found("No time tracking session is running"))?;
+    save(&mut project).await?;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "session_minutes": duration.num_minutes(),
+            "total_minutes": project.total_time_logged().num_minutes(),
+        }));
+    }
+
+    println!("{} Stopped tracking time for \"{}\"",
+        "⏹️".bright_blue(),
+        project.name.bright_white().bold()
+    );
+    println!("  Session: {}", format_duration_hm(&duration).bright_green());
+    println!("  Total: {}", format_duration_hm(&project.total_time_logged()).bright_cyan());
+
+    Ok(())
+}
+
+async fn show_time(project_name: String) -> Result<()> {
+    let project = find_project(&project_name).await?;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "entries": project.time_entries.iter()
+                .map(|entry| serde_json::json!({
+                    "start": timestamp(&entry.start),
+                    "end": entry.end.as_ref().map(timestamp),
+                    "minutes": entry.duration().num_minutes(),
+                    "running": entry.is_running(),
+                    "note": entry.note,
+                }))
+                .collect::<Vec<_>>(),
+            "total_minutes": project.total_time_logged().num_minutes(),
+        }));
+    }
+
+    println!("{} Time Log for \"{}\"",
+        "⏱️".bright_blue(),
+        project.name.bright_white().bold()
+    );
+    println!();
+
+    if project.time_entries.is_empty() {
+        println!("{}", "No time logged yet.".bright_yellow());
+        println!("{} Use {} or {}",
+            "💡".bright_blue(),
+            format!("'opj start \"{}\"'", project.name).bright_cyan(),
+            format!("'opj time add \"{}\" 1h30m'", project.name).bright_cyan()
+        );
+        return Ok(());
+    }
+
+    for entry in &project.time_entries {
+        let start = entry.start.with_timezone(&chrono::Local);
+        let end = match entry.end {
+            Some(end) => end.with_timezone(&chrono::Local).format("%H:%M").to_string(),
+            None => "running".to_string(),
+        };
+
+        let duration = format_duration_hm(&entry.duration());
+        println!("  {} {}-{} {:>8}{}",
+            start.format("%Y-%m-%d").to_string().bright_black(),
+            start.format("%H:%M"),
+            end,
+            if entry.is_running() { duration.bright_yellow() } else { duration.bright_green() },
+            entry.note.as_ref()
+                .map(|n| format!("  {}", n))
+                .unwrap_or_default()
+                .bright_cyan()
+        );
+    }
+
+    println!();
+    println!("  Total: {} in {} {}",
+        format_duration_hm(&project.total_time_logged()).bright_white().bold(),
+        project.time_entries.len(),
+        if project.time_entries.len() == 1 { "session" } else { "sessions" }
+    );
+
+    Ok(())
+}
+
+async fn add_time(project_name: String, duration: String, note: Option<String>) -> Result<()> {
+    let duration = parse_duration(&duration).map_err(error::invalid_argument)?;
+    if duration <= chrono::Duration::zero() {
+        return Err(error::invalid_argument("Duration must be positive"));
+    }
+
+    let mut project = find_project(&project_name).await?;
+    project.add_time_entry(duration, note)?;
+    save(&mut project).await?;
+
+    if json_output() {
+        return emit_json(serde_json::json!({
+            "project": project_ref(&project),
+            "logged_minutes": duration.num_minutes(),
+            "total_minutes": project.total_time_logged().num_minutes(),
+        }));
+    }
+
+    println!("{} Logged {} for \"{}\"",
+        "✅".bright_green(),
+        format_duration_hm(&duration).bright_green(),
+        project.name.bright_white().bold()
+    );
+    println!("  Total: {}", format_duration_hm(&project.total_time_logged()).bright_cyan());
+
+    Ok(())
+}
+
+async fn find_project(project_name: &str) -> Result<Project> {
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+
+    scanner.find_project_by_name(project_name).await?
+        .ok_or_else(|| error::not_found(format!("Project '{}' not found", project_name)))
+}
+
+async fn save(project: &mut Project) -> Result<()> {
+    let project_path = project.path.clone()
+        .ok_or_else(|| anyhow::anyhow!("Project '{}' has no directory", project.name))?;
+    core::save_project_metadata(project, &project_path).await
+}
@@ -0,0 +1,9 @@
+use anyhow::Result;
+use crate::config::Settings;
+use crate::utils::output::require_text_output;
+
+pub async fn execute() -> Result<()> {
+    require_text_output("The interactive interface")?;
+    let settings = Settings::load().await?;
+    crate::tui::run(settings).await
+}
@@ -0,0 +1,137 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use colored::*;
+use notify::{RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::config::Settings;
+use crate::core::{self, Project};
+use crate::fs::history;
+use crate::fs::scanner::{self, ProjectScanner};
+use crate::fs::watcher::{self, ProjectChange};
+use crate::utils::markdown::DASHBOARD_FILE;
+use crate::utils::output::require_text_output;
+
+pub async fn execute(dashboard: bool, debounce: u64) -> Result<()> {
+    require_text_output("Watch mode")?;
+    let settings = Settings::load().await?;
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let dashboard_path = settings.projects_path().join(DASHBOARD_FILE);
+    let debounce = Duration::from_millis(debounce);
+
+    // Also brings the index up to date before the first change comes in
+    let projects = scanner.scan_projects().await?;
+
+    let (sender, mut events) = tokio::sync::mpsc::unbounded_channel();
+    let mut fs_watcher = notify::recommended_watcher(move |event| {
+        let _ = sender.send(event);
+    }).context("Failed to start the file watcher")?;
+    for root in scanner.roots().iter().filter(|root| root.is_dir()) {
+        fs_watcher.watch(root, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", root.display()))?;
+    }
+
+    println!("{} Watching {} project(s) in {}",
+        "👀".bright_cyan(),
+        projects.len(),
+        scanner.roots().iter().map(|root| root.display().to_string()).collect::<Vec<_>>().join(", ").bright_cyan()
+    );
+    println!("{}", "Press Ctrl+C to stop".bright_black());
+    if dashboard {
+        refresh_dashboard(&settings, &projects).await;
+    }
+
+    loop {
+        let first = tokio::select! {
+            event = events.recv() => event,
+            _ = tokio::signal::ctrl_c() => None,
+        };
+        let Some(first) = first else { break };
+
+        // Wait for a quiet moment, so one save touching several files is handled once
+        let mut batch = vec![first];
+        while let Ok(Some(event)) = tokio::time::timeout(debounce, events.recv()).await {
+            batch.push(event);
+        }
+
+        let mut paths: Vec<PathBuf> = Vec::new();
+        for event in batch {
+            match event {
+                Ok(event) if watcher::is_content_change(&event.kind) => paths.extend(event.paths),
+                Ok(_) => {}
+                Err(e) => eprintln!("{} {}", "⚠️ Watch error:".bright_yellow(), e),
+            }
+        }
+        paths.retain(|path| !watcher::is_ignored(path, scanner.roots(), std::slice::from_ref(&dashboard_path)));
+        if paths.is_empty() {
+            continue;
+        }
+
+        let mut projects = match scanner.scan_projects().await {
+            Ok(projects) => projects,
+            Err(e) => {
+                eprintln!("{} {:#}", "❌ Scan failed:".bright_red(), e);
+                continue;
+            }
+        };
+
+        let dirs: Vec<PathBuf> = projects.iter().filter_map(|p| p.path.clone()).collect();
+        for (dir, change) in watcher::project_changes(&paths, &dirs) {
+            let Some(project) = projects.iter_mut().find(|p| p.path.as_ref() == Some(&dir)) else { continue };
+            if let Err(e) = apply_change(project, &dir, &change).await {
+                eprintln!("{} {}: {:#}", "❌".bright_red(), project.name, e);
+            }
+        }
+
+        if dashboard {
+            refresh_dashboard(&settings, &projects).await;
+        }
+        // One undoable operation per batch
+        if let Err(e) = history::finish() {
+            eprintln!("{} {:#}", "⚠️ Could not save the undo history:".bright_yellow(), e);
+        }
+    }
+
+    println!("{} Stopped watching", "👋".bright_cyan());
+    Ok(())
+}
+
+/// Sync overview edits into the metadata and mark the project as updated.
+/// Projects without metadata are derived from their notes on every scan.
+async fn apply_change(project: &mut Project, dir: &Path, change: &ProjectChange) -> Result<()> {
+    if !dir.join(core::METADATA_FILE).exists() {
+        return Ok(());
+    }
+
+    let mut changes = Vec::new();
+    if change.overview {
+        if let Some(fields) = scanner::overview_fields(dir) {
+            changes = core::sync_from_overview(project, &fields);
+        }
+    }
+    if changes.is_empty() && change.notes {
+        project.updated = Utc::now();
+        changes.push("notes changed".to_string());
+    }
+    core::save_project_metadata(project, dir).await?;
+
+    println!("{} {} {} {}",
+        time_stamp(),
+        "🔄".bright_blue(),
+        project.name.bright_white().bold(),
+        changes.join(", ").bright_black()
+    );
+    Ok(())
+}
+
+async fn refresh_dashboard(settings: &Settings, projects: &[Project]) {
+    match super::dashboard::refresh(settings, projects).await {
+        Ok(true) => println!("{} {} Dashboard updated", time_stamp(), "📊".bright_blue()),
+        Ok(false) => {}
+        Err(e) => eprintln!("{} {:#}", "❌ Dashboard update failed:".bright_red(), e),
+    }
+}
+
+fn time_stamp() -> ColoredString {
+    chrono::Local::now().format("%H:%M:%S").to_string().bright_black()
+}
@@ -30,9 +30,17 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub dry_run: bool,
 
+    /// Write even if files changed since opj read them
+    #[arg(long, global = true)]
+    pub force: bool,
+
     /// Disable colored output
     #[arg(long, global = true)]
     pub no_color: bool,
+
+    /// Print a versioned JSON envelope instead of text (see docs/json-output.md)
+    #[arg(long, global = true)]
+    pub json: bool,
 }
 
 #[derive(Subcommand)]
@@ -65,6 +73,14 @@ pub enum Commands {
         /// Interactive mode
         #[arg(short, long)]
         interactive: bool,
+
+        /// Create the project as a sub-project of an existing one
+        #[arg(long)]
+        parent: Option<String>,
+
+        /// Due date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
     },
 
     /// List existing projects
@@ -81,6 +97,10 @@ pub enum Commands {
         #[arg(short, long)]
         priority: Option<Priority>,
 
+        /// Filter expression, e.g. "status:development priority:high tag:rust updated:<14d"
+        #[arg(long)]
+        query: Option<String>,
+
         /// Sort by field
         #[arg(long, default_value = "updated")]
         sort: SortField,
@@ -88,6 +108,46 @@ pub enum Commands {
         /// Output format
         #[arg(short, long, default_value = "table")]
         format: OutputFormat,
+
+        /// Fields to output, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        columns: Vec<Column>,
+
+        /// Print one line per project from a template, e.g. '{{name}}\t{{status}}'
+        #[arg(long, conflicts_with_all = ["format", "columns"])]
+        template: Option<String>,
+
+        /// Show sub-projects nested under their parents
+        #[arg(long, conflicts_with_all = ["format", "columns", "template"])]
+        tree: bool,
+
+        /// Fail instead of skipping project directories that cannot be loaded
+        #[arg(long)]
+        strict: bool,
+
+        /// Also show projects in the archive folder
+        #[arg(long)]
+        include_archived: bool,
+    },
+
+    /// Set a project to archived and move it into the archive folder
+    Archive {
+        /// Project name or ID
+        project: String,
+
+        /// Note for the status change
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+
+    /// Move an archived project back into the projects folder
+    Unarchive {
+        /// Project name or ID
+        project: String,
+
+        /// Status to restore (default: the one it had before archiving)
+        #[arg(short, long)]
+        status: Option<ProjectStatus>,
     },
 
     /// Manage project status
@@ -112,6 +172,14 @@ pub enum Commands {
     Info {
         /// Project name or ID
         project: String,
+
+        /// Output format
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+
+        /// Fields to output, comma-separated (default: all)
+        #[arg(long, value_delimiter = ',')]
+        columns: Vec<Column>,
     },
 
     /// Manage templates
@@ -136,7 +204,279 @@ pub enum Commands {
     },
 
     /// Show project statistics
-    Stats,
+    Stats {
+        /// Output format
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+
+        /// Filter expression, e.g. "status:development priority:high tag:rust updated:<14d"
+        #[arg(long)]
+        query: Option<String>,
+
+        /// Show throughput, cycle time, time in status and WIP over time
+        #[arg(long)]
+        trends: bool,
+
+        /// Bucket size for --trends
+        #[arg(long, default_value = "week", requires = "trends")]
+        interval: TrendInterval,
+
+        /// Number of weeks or months shown by --trends
+        #[arg(long, default_value_t = 12, requires = "trends")]
+        last: usize,
+
+        /// Fail instead of skipping project directories that cannot be loaded
+        #[arg(long)]
+        strict: bool,
+
+        /// Also show projects in the archive folder
+        #[arg(long)]
+        include_archived: bool,
+    },
+
+    /// Find projects matching a filter expression
+    Search {
+        /// Query terms, e.g. tag:rust -status:archived (put options before the query)
+        #[arg(required = true, num_args = 1.., allow_hyphen_values = true)]
+        query: Vec<String>,
+
+        /// Output format
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+
+        /// Fields to output, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        columns: Vec<Column>,
+
+        /// Fail instead of skipping project directories that cannot be loaded
+        #[arg(long)]
+        strict: bool,
+
+        /// Also show projects in the archive folder
+        #[arg(long)]
+        include_archived: bool,
+    },
+
+    /// Manage saved queries (used as @name in --query)
+    Query {
+        #[command(subcommand)]
+        subcommand: QueryCommand,
+    },
+
+    /// Manage checkbox tasks in project notes
+    Tasks {
+        #[command(subcommand)]
+        subcommand: TasksCommand,
+    },
+
+    /// Start tracking time for a project
+    Start {
+        /// Project name or ID
+        project: String,
+
+        /// Note for the work session
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+
+    /// Stop the running time tracking session
+    Stop {
+        /// Note for the work session
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+
+    /// Show or log time spent on a project
+    #[command(args_conflicts_with_subcommands = true)]
+    Time {
+        /// Project name or ID
+        project: Option<String>,
+
+        #[command(subcommand)]
+        subcommand: Option<TimeCommand>,
+    },
+
+    /// Link two projects (e.g. a Web-App that depends on an API)
+    Link {
+        /// Project that holds the link
+        from: String,
+
+        /// Project being linked to
+        to: String,
+
+        /// Relation kind
+        #[arg(short, long, default_value = "depends-on")]
+        kind: LinkKind,
+
+        /// Remove the link instead of adding it
+        #[arg(long)]
+        remove: bool,
+    },
+
+    /// Export the project relation graph
+    Graph {
+        /// Graph format
+        #[arg(short, long, default_value = "mermaid")]
+        format: GraphFormat,
+
+        /// Filter by status
+        #[arg(short, long)]
+        status: Option<ProjectStatus>,
+
+        /// Filter by project type
+        #[arg(short = 't', long)]
+        project_type: Option<ProjectType>,
+
+        /// Write the graph to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Write the Mermaid graph into a dashboard note (relative to the vault)
+        #[arg(long, num_args = 0..=1, default_missing_value = "")]
+        dashboard: Option<String>,
+    },
+
+    /// Write or refresh the Projects/_Dashboard.md index note
+    Dashboard,
+
+    /// Watch the vault and keep metadata, the index and the dashboard current
+    Watch {
+        /// Regenerate the dashboard note after every change
+        #[arg(long)]
+        dashboard: bool,
+
+        /// Milliseconds to wait for further changes before reacting
+        #[arg(long, default_value_t = 500)]
+        debounce: u64,
+    },
+
+    /// List stale, overdue, stuck and inconsistent projects with reasons
+    Attention {
+        /// Only show these problems (comma-separated)
+        #[arg(short, long, value_delimiter = ',')]
+        kind: Vec<AttentionFilter>,
+
+        /// Filter expression, e.g. "priority:high tag:rust"
+        #[arg(long)]
+        query: Option<String>,
+    },
+
+    /// Check the vault for broken metadata, duplicate IDs and other problems
+    Doctor {
+        /// Repair the issues that can be fixed safely
+        #[arg(long)]
+        fix: bool,
+    },
+
+    /// Back up project folders and metadata to a .tar.zst archive
+    #[command(args_conflicts_with_subcommands = true)]
+    Backup {
+        #[command(subcommand)]
+        subcommand: Option<BackupCommand>,
+
+        /// Only back up this project (name or ID)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Archive to write (default: <vault>/.opj/backups/opj-<timestamp>.tar.zst)
+        #[arg(short, long)]
+        out: Option<String>,
+    },
+
+    /// Restore projects from a backup archive
+    Restore {
+        /// Backup archive (.tar.zst)
+        archive: String,
+
+        /// Only restore this project (name or ID)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// What to do with projects that already exist in the vault
+        #[arg(long, default_value = "fail")]
+        on_conflict: ConflictPolicy,
+    },
+
+    /// Show recent changes opj made to the vault
+    History {
+        /// Number of operations to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+
+    /// Revert the last operations recorded in the history
+    Undo {
+        /// Number of operations to revert
+        #[arg(default_value_t = 1)]
+        count: usize,
+    },
+
+    /// Manage the on-disk project index
+    Cache {
+        #[command(subcommand)]
+        subcommand: CacheCommand,
+    },
+
+    /// Generate a progress report for a period
+    Report {
+        /// Reporting period
+        #[arg(short, long, default_value = "week")]
+        period: ReportPeriod,
+
+        /// First day of a custom period (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<String>,
+
+        /// Last day of a custom period (YYYY-MM-DD, default: today)
+        #[arg(long)]
+        to: Option<String>,
+
+        /// Report format
+        #[arg(short, long, default_value = "md")]
+        format: ReportFormat,
+
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Save the report as a vault note (default: Projects/_Reports/<start>_<end>.md)
+        #[arg(long, num_args = 0..=1, default_missing_value = "")]
+        note: Option<String>,
+
+        /// Filter expression, e.g. "status:development priority:high tag:rust updated:<14d"
+        #[arg(long)]
+        query: Option<String>,
+    },
+
+    /// Open the interactive terminal dashboard
+    Tui,
+
+    /// Show projects as a kanban board with one column per status
+    Board {
+        /// Columns to show (repeatable); defaults to the configured status values
+        #[arg(short, long)]
+        status: Vec<ProjectStatus>,
+
+        /// Move cards between columns interactively
+        #[arg(short, long)]
+        interactive: bool,
+
+        /// Filter expression, e.g. "status:development priority:high tag:rust updated:<14d"
+        #[arg(long)]
+        query: Option<String>,
+    },
+
+    /// Export projects to other formats
+    Export {
+        #[command(subcommand)]
+        subcommand: ExportCommand,
+    },
+
+    /// Import changes made outside opj
+    Import {
+        #[command(subcommand)]
+        subcommand: ImportCommand,
+    },
 }
 
 #[derive(Subcommand)]
@@ -166,6 +506,131 @@ pub enum TemplateCommand {
     },
 }
 
+#[derive(Subcommand)]
+pub enum TasksCommand {
+    /// List checkbox tasks found in project notes
+    List {
+        /// Project name or ID
+        project: String,
+
+        /// Only show open tasks
+        #[arg(long)]
+        pending: bool,
+    },
+
+    /// Mark a task as done
+    Done {
+        /// Project name or ID
+        project: String,
+
+        /// Task ID as shown by 'opj tasks list'
+        task_id: usize,
+    },
+
+    /// Mark a task as not done
+    Undone {
+        /// Project name or ID
+        project: String,
+
+        /// Task ID as shown by 'opj tasks list'
+        task_id: usize,
+    },
+
+    /// Add a new task to a project note
+    Add {
+        /// Project name or ID
+        project: String,
+
+        /// Task text
+        text: String,
+
+        /// Note to add the task to
+        #[arg(long, default_value = "00_PROJECT-OVERVIEW.md")]
+        file: String,
+
+        /// Section heading to add the task under (e.g. "## Goals")
+        #[arg(long)]
+        section: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TimeCommand {
+    /// Log time manually
+    Add {
+        /// Project name or ID
+        project: String,
+
+        /// Duration, e.g. 1h30m, 45m, 1.5h
+        duration: String,
+
+        /// Note for the work session
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum QueryCommand {
+    /// List saved queries
+    List,
+
+    /// Save a query under a name
+    Save {
+        /// Query name, used as @name
+        name: String,
+
+        /// Query expression
+        #[arg(required = true, num_args = 1.., allow_hyphen_values = true)]
+        query: Vec<String>,
+    },
+
+    /// Delete a saved query
+    Remove {
+        /// Query name
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BackupCommand {
+    /// List backups in <vault>/.opj/backups
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Discard the index and reparse every project
+    Rebuild,
+
+    /// Delete the index
+    Clear,
+}
+
+#[derive(Subcommand)]
+pub enum ExportCommand {
+    /// Write an Obsidian Kanban plugin board note
+    Kanban {
+        /// Board note path, relative to the vault (default: Projects/Board.md)
+        #[arg(short, long)]
+        out: Option<String>,
+
+        /// Lanes to include (repeatable); defaults to the configured status values
+        #[arg(short, long)]
+        status: Vec<ProjectStatus>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ImportCommand {
+    /// Update project statuses from the lanes of a Kanban board note
+    Kanban {
+        /// Board note path, relative to the vault (default: Projects/Board.md)
+        #[arg(short, long)]
+        file: Option<String>,
+    },
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 pub enum ProjectType {
     WebApp,
@@ -192,6 +657,64 @@ pub enum Priority {
     High,
 }
 
+#[derive(Clone, Debug, ValueEnum)]
+pub enum LinkKind {
+    DependsOn,
+    Related,
+    /// Make the first project a sub-project of the second
+    Parent,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ConflictPolicy {
+    /// Restore nothing if any project already exists
+    Fail,
+    /// Keep existing projects and restore the rest
+    Skip,
+    /// Write the backed-up files over existing ones
+    Overwrite,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum AttentionFilter {
+    /// Past the due date
+    Overdue,
+    /// No metadata update or note edit within the status threshold
+    Stale,
+    /// In the current status for longer than allowed
+    LongInStatus,
+    /// Metadata that contradicts itself or the overview note
+    Inconsistent,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ReportPeriod {
+    /// The current week so far
+    Week,
+    /// The current month so far
+    Month,
+    /// --from to --to
+    Custom,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum TrendInterval {
+    Week,
+    Month,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ReportFormat {
+    Md,
+    Html,
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 pub enum SortField {
     Name,
@@ -207,4 +730,29 @@ pub enum OutputFormat {
     Table,
     Json,
     Yaml,
+    Csv,
+    Tsv,
+    /// Markdown table
+    Markdown,
+    /// Newline-delimited JSON, one object per line
+    Ndjson,
+}
+
+/// Project fields selectable with `--columns`
+#[derive(Clone, Debug, PartialEq, ValueEnum)]
+pub enum Column {
+    Id,
+    Name,
+    Type,
+    Status,
+    Priority,
+    Path,
+    Tags,
+    Technologies,
+    Created,
+    Updated,
+    Due,
+    Progress,
+    DaysSinceUpdate,
+    Description,
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use anyhow::{Result, Context};
 
@@ -7,12 +8,53 @@ pub struct Settings {
     pub obsidian_path: PathBuf,
     pub projects_dir: String,
     pub templates_dir: String,
+    /// Where `opj archive` moves projects, relative to the vault
+    #[serde(default = "default_archive_dir")]
+    pub archive_dir: String,
     pub default_type: crate::cli::ProjectType,
     pub default_priority: crate::cli::Priority,
     pub auto_git_init: bool,
     pub editor: Option<String>,
     pub date_format: String,
     pub status_values: Vec<String>,
+    /// Named filter expressions, usable as `@name` in `--query`
+    #[serde(default)]
+    pub saved_queries: BTreeMap<String, String>,
+    /// Thresholds for `opj attention`
+    #[serde(default)]
+    pub attention: crate::core::AttentionThresholds,
+    /// Where and how deep to look for projects
+    #[serde(default)]
+    pub scan: ScanSettings,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct ScanSettings {
+    /// Directories to search, relative to the vault. Empty means `projects_dir`.
+    pub roots: Vec<String>,
+    /// How many folder levels below a root to search for projects
+    pub max_depth: usize,
+    /// Glob patterns of folders to skip, matched against the path relative to
+    /// the root (`2023/**`) or, without a `/`, against the folder name
+    pub ignore: Vec<String>,
+    /// Keep parsed projects in `<vault>/.opj/cache` and reparse only changed ones
+    pub cache: bool,
+}
+
+impl Default for ScanSettings {
+    fn default() -> Self {
+        Self {
+            roots: Vec::new(),
+            max_depth: 3,
+            ignore: Vec::new(),
+            cache: true,
+        }
+    }
+}
+
+fn default_archive_dir() -> String {
+    "Projects/_ARCHIVE".to_string()
 }
 
 impl Default for Settings {
@@ -24,6 +66,7 @@ impl Default for Settings {
                 .join("Obsidian-Vault"),
             projects_dir: "Projects".to_string(),
             templates_dir: "Projects/_TEMPLATES".to_string(),
+            archive_dir: default_archive_dir(),
             default_type: crate::cli::ProjectType::WebApp,
             default_priority: crate::cli::Priority::Medium,
             auto_git_init: true,
@@ -38,6 +81,9 @@ impl Default for Settings {
                 "archived".to_string(),
                 "cancelled".to_string(),
             ],
+            saved_queries: BTreeMap::new(),
+            attention: crate::core::AttentionThresholds::default(),
+            scan: ScanSettings::default(),
         }
     }
 }
@@ -46,20 +92,22 @@ impl Settings {
     pub async fn load() -> Result<Self> {
         let config_path = Self::config_file_path()?;
         
-        if config_path.exists() {
+        let settings = if config_path.exists() {
             let content = tokio::fs::read_to_string(&config_path).await
                 .context("Failed to read config file")?;
             
-            let settings: Settings = serde_yaml::from_str(&content)
-                .context("Failed to parse config file")?;
-                
-            Ok(settings)
+            serde_yaml::from_str(&content)
+                .context("Failed to parse config file")?
         } else {
             // Create default config
             let settings = Self::default();
             settings.save().await?;
-            Ok(settings)
-        }
+            settings
+        };
+
+        // From here on, writes into the vault are journaled for `opj undo`
+        crate::fs::history::set_vault(&settings.obsidian_path);
+        Ok(settings)
     }
 
     pub async fn save(&self) -> Result<()> {
@@ -91,6 +139,23 @@ impl Settings {
         self.obsidian_path.join(&self.projects_dir)
     }
 
+    /// Directories scanned for projects.
+    pub fn project_roots(&self) -> Vec<PathBuf> {
+        if self.scan.roots.is_empty() {
+            return vec![self.projects_path()];
+        }
+        self.scan.roots.iter().map(|root| self.obsidian_path.join(root)).collect()
+    }
+
+    /// Where the project index is kept.
+    pub fn cache_path(&self) -> PathBuf {
+        self.obsidian_path.join(crate::fs::cache::CACHE_DIR)
+    }
+
+    pub fn archive_path(&self) -> PathBuf {
+        self.obsidian_path.join(&self.archive_dir)
+    }
+
     pub fn templates_path(&self) -> PathBuf {
         self.obsidian_path.join(&self.templates_dir)
     }
@@ -148,6 +213,11 @@ impl Settings {
         Ok(())
     }
 
+    /// Parse a `--query` expression, expanding saved queries.
+    pub fn parse_query(&self, query: Option<&str>) -> Result<crate::core::ProjectQuery> {
+        crate::core::ProjectQuery::parse(query.unwrap_or_default(), &self.saved_queries)
+    }
+
     pub fn get_template_path(&self, template_name: &str) -> PathBuf {
         self.templates_path().join(template_name)
     }
@@ -0,0 +1,85 @@
+// Where `opj archive` moves projects, and what `opj unarchive` restores
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::core::{Project, ProjectStatus};
+
+/// Folder an archived project moves to: `<archive>/<year>/<folder name>`.
+pub fn archive_destination(archive_path: &Path, project_dir: &Path, year: i32) -> Option<PathBuf> {
+    Some(archive_path.join(year.to_string()).join(project_dir.file_name()?))
+}
+
+/// Folder an archived project returns to: where it was archived from, or
+/// straight under `projects_path` when that was not recorded.
+pub fn restore_destination(vault_path: &Path, projects_path: &Path, project_dir: &Path, archived_from: Option<&str>) -> Option<PathBuf> {
+    match archived_from {
+        // Only paths written by `opj archive`; a hand-edited `..` is not followed
+        Some(from) if !Path::new(from).components().any(|c| matches!(c, Component::ParentDir)) => {
+            Some(vault_path.join(from))
+        }
+        _ => Some(projects_path.join(project_dir.file_name()?)),
+    }
+}
+
+/// Whether a project folder lies in the archive.
+pub fn is_in_archive(archive_path: &Path, project_dir: &Path) -> bool {
+    project_dir.starts_with(archive_path)
+}
+
+/// The status a project had before it was archived, from its history.
+/// Projects archived without a recorded status come back as completed.
+pub fn status_before_archive(project: &Project) -> ProjectStatus {
+    project.status_history.iter().rev()
+        .map(|entry| &entry.status)
+        .find(|status| **status != ProjectStatus::Archived)
+        .cloned()
+        .unwrap_or(ProjectStatus::Completed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_paths() {
+        let archive = Path::new("/vault/Projects/_ARCHIVE");
+        let dir = Path::new("/vault/Projects/2025-06-18_Tool_Cli");
+        let destination = archive_destination(archive, dir, 2026).unwrap();
+
+        assert_eq!(destination, Path::new("/vault/Projects/_ARCHIVE/2026/2025-06-18_Tool_Cli"));
+        assert!(is_in_archive(archive, &destination));
+        assert!(!is_in_archive(archive, dir));
+    }
+
+    #[test]
+    fn test_restore_destination() {
+        let vault = Path::new("/vault");
+        let projects = Path::new("/vault/Projects");
+        let dir = Path::new("/vault/Projects/_ARCHIVE/2026/Nested");
+
+        assert_eq!(
+            restore_destination(vault, projects, dir, Some("Projects/2025/Nested")).unwrap(),
+            Path::new("/vault/Projects/2025/Nested")
+        );
+        assert_eq!(
+            restore_destination(vault, projects, dir, Some("/work/Nested")).unwrap(),
+            Path::new("/work/Nested")
+        );
+        assert_eq!(restore_destination(vault, projects, dir, None).unwrap(), Path::new("/vault/Projects/Nested"));
+        assert_eq!(
+            restore_destination(vault, projects, dir, Some("../Nested")).unwrap(),
+            Path::new("/vault/Projects/Nested")
+        );
+    }
+
+    #[test]
+    fn test_status_before_archive() {
+        let mut project = crate::core::fixture::project("cli");
+        project.status_history.clear();
+        assert_eq!(status_before_archive(&project), ProjectStatus::Completed);
+
+        project.update_status(ProjectStatus::Testing, None);
+        project.update_status(ProjectStatus::Archived, None);
+        assert_eq!(status_before_archive(&project), ProjectStatus::Testing);
+    }
+}
@@ -0,0 +1,279 @@
+// Projects that need a look: stale, overdue, stuck or inconsistent
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::core::{find_by_id, Project, ProjectStatus, STALE_AFTER_DAYS};
+
+/// Per-status limits, keyed by lowercase status name (`development`, ...).
+/// Statuses without an entry are never flagged for that check.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AttentionThresholds {
+    /// Days without a metadata update or note edit before a project is stale
+    pub stale_days: BTreeMap<String, i64>,
+    /// Days a project may stay in one status
+    pub max_days_in_status: BTreeMap<String, i64>,
+}
+
+impl Default for AttentionThresholds {
+    fn default() -> Self {
+        let days = |pairs: &[(&str, i64)]| pairs.iter().map(|(status, days)| (status.to_string(), *days)).collect();
+
+        Self {
+            stale_days: days(&[
+                ("planning", STALE_AFTER_DAYS),
+                ("development", STALE_AFTER_DAYS),
+                ("testing", STALE_AFTER_DAYS),
+            ]),
+            max_days_in_status: days(&[
+                ("idea", 180),
+                ("planning", 30),
+                ("development", 90),
+                ("testing", 30),
+            ]),
+        }
+    }
+}
+
+impl AttentionThresholds {
+    pub fn stale_days_for(&self, status: &ProjectStatus) -> Option<i64> {
+        self.stale_days.get(&status_key(status)).copied()
+    }
+
+    pub fn max_days_in(&self, status: &ProjectStatus) -> Option<i64> {
+        self.max_days_in_status.get(&status_key(status)).copied()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AttentionKind {
+    Overdue,
+    Stale,
+    LongInStatus,
+    Inconsistent,
+}
+
+impl std::fmt::Display for AttentionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AttentionKind::Overdue => write!(f, "overdue"),
+            AttentionKind::Stale => write!(f, "stale"),
+            AttentionKind::LongInStatus => write!(f, "long-in-status"),
+            AttentionKind::Inconsistent => write!(f, "inconsistent"),
+        }
+    }
+}
+
+impl From<crate::cli::AttentionFilter> for AttentionKind {
+    fn from(cli_kind: crate::cli::AttentionFilter) -> Self {
+        match cli_kind {
+            crate::cli::AttentionFilter::Overdue => AttentionKind::Overdue,
+            crate::cli::AttentionFilter::Stale => AttentionKind::Stale,
+            crate::cli::AttentionFilter::LongInStatus => AttentionKind::LongInStatus,
+            crate::cli::AttentionFilter::Inconsistent => AttentionKind::Inconsistent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttentionReason {
+    pub kind: AttentionKind,
+    pub message: String,
+}
+
+/// A project with everything that is wrong with it, most urgent first.
+pub struct AttentionItem<'a> {
+    pub project: &'a Project,
+    pub reasons: Vec<AttentionReason>,
+}
+
+/// Check every project. `note_statuses` holds the status written in each
+/// project's overview frontmatter, by project id, where there is one.
+pub fn find_attention<'a>(
+    projects: &'a [Project],
+    thresholds: &AttentionThresholds,
+    note_statuses: &BTreeMap<String, ProjectStatus>,
+    now: DateTime<Utc>,
+) -> Vec<AttentionItem<'a>> {
+    let mut items: Vec<AttentionItem> = projects.iter()
+        .map(|project| {
+            let mut reasons = check_project(project, projects, thresholds, now);
+            if let Some(note_status) = note_statuses.get(&project.id) {
+                if *note_status != project.status {
+                    reasons.push(inconsistent(format!(
+                        "overview note says {} but metadata says {}",
+                        note_status, project.status
+                    )));
+                }
+            }
+            reasons.sort_by_key(|reason| reason.kind);
+            AttentionItem { project, reasons }
+        })
+        .filter(|item| !item.reasons.is_empty())
+        .collect();
+
+    items.sort_by(|a, b| {
+        a.reasons[0].kind.cmp(&b.reasons[0].kind)
+            .then(a.project.last_activity().cmp(&b.project.last_activity()))
+    });
+    items
+}
+
+fn check_project(
+    project: &Project,
+    projects: &[Project],
+    thresholds: &AttentionThresholds,
+    now: DateTime<Utc>,
+) -> Vec<AttentionReason> {
+    let mut reasons = Vec::new();
+    let finished = matches!(
+        project.status,
+        ProjectStatus::Completed | ProjectStatus::Archived | ProjectStatus::Cancelled
+    );
+
+    if let Some(due) = project.due.filter(|due| !finished && *due < now.date_naive()) {
+        let days = (now.date_naive() - due).num_days();
+        reasons.push(AttentionReason {
+            kind: AttentionKind::Overdue,
+            message: format!("due {} ({} {} ago)", due.format("%Y-%m-%d"), days, plural_days(days)),
+        });
+    }
+
+    if let Some(limit) = thresholds.stale_days_for(&project.status) {
+        let idle = now.signed_duration_since(project.last_activity()).num_days();
+        if idle > limit {
+            reasons.push(AttentionReason {
+                kind: AttentionKind::Stale,
+                message: format!(
+                    "no updates or note edits for {} {} (limit {} in {})",
+                    idle, plural_days(idle), limit, project.status
+                ),
+            });
+        }
+    }
+
+    if let Some(limit) = thresholds.max_days_in(&project.status) {
+        let days = now.signed_duration_since(project.status_since()).num_days();
+        if days > limit {
+            reasons.push(AttentionReason {
+                kind: AttentionKind::LongInStatus,
+                message: format!("in {} for {} {} (limit {})", project.status, days, plural_days(days), limit),
+            });
+        }
+    }
+
+    if project.status_history.last().is_none_or(|entry| entry.status != project.status) {
+        reasons.push(inconsistent(format!("status history does not end in {}", project.status)));
+    }
+
+    let relations = project.parent.iter().map(|id| ("parent", id))
+        .chain(project.depends_on.iter().map(|id| ("dependency", id)))
+        .chain(project.related.iter().map(|id| ("related project", id)));
+    for (relation, id) in relations {
+        if find_by_id(projects, id).is_none() {
+            reasons.push(inconsistent(format!("{} {} does not exist", relation, id)));
+        }
+    }
+
+    if finished && project.active_time_entry().is_some() {
+        reasons.push(inconsistent(format!("time tracking is still running although {}", project.status)));
+    }
+
+    if project.updated < project.created {
+        reasons.push(inconsistent("last update is before creation".to_string()));
+    }
+
+    reasons
+}
+
+fn inconsistent(message: String) -> AttentionReason {
+    AttentionReason { kind: AttentionKind::Inconsistent, message }
+}
+
+fn status_key(status: &ProjectStatus) -> String {
+    status.to_string().to_lowercase()
+}
+
+fn plural_days(days: i64) -> &'static str {
+    if days == 1 { "day" } else { "days" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    fn kinds(item: &AttentionItem) -> Vec<AttentionKind> {
+        item.reasons.iter().map(|reason| reason.kind).collect()
+    }
+
+    #[test]
+    fn test_stale_uses_last_note_edit() {
+        let now = Utc::now();
+        let mut idle = project("idle").with_status(ProjectStatus::Development);
+        idle.created = now - Duration::days(20);
+        idle.updated = now - Duration::days(10);
+        let mut edited = idle.clone();
+        edited.id = "edited".to_string();
+        edited.last_edited = Some(now - Duration::days(1));
+
+        let projects = vec![idle, edited];
+        let items = find_attention(&projects, &AttentionThresholds::default(), &BTreeMap::new(), now);
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].project.name, "idle");
+        assert_eq!(kinds(&items[0]), vec![AttentionKind::Stale]);
+        assert!(items[0].reasons[0].message.contains("10 days (limit 7 in Development)"));
+    }
+
+    #[test]
+    fn test_overdue_and_long_in_status() {
+        let now = Utc::now();
+        let mut late = project("late").with_status(ProjectStatus::Testing);
+        late.due = Some(now.date_naive() - Duration::days(3));
+        late.status_history.last_mut().unwrap().timestamp = now - Duration::days(45);
+
+        let mut done = project("done").with_status(ProjectStatus::Completed);
+        done.due = Some(now.date_naive() - Duration::days(3));
+
+        let projects = vec![done, late];
+        let items = find_attention(&projects, &AttentionThresholds::default(), &BTreeMap::new(), now);
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(kinds(&items[0]), vec![AttentionKind::Overdue, AttentionKind::LongInStatus]);
+        assert!(items[0].reasons[1].message.contains("in Testing for 45 days (limit 30)"));
+    }
+
+    #[test]
+    fn test_inconsistencies() {
+        let now = Utc::now();
+        let mut broken = project("broken").with_status(ProjectStatus::Completed);
+        broken.depends_on.push("missing-id".to_string());
+        broken.start_time_tracking(None).unwrap();
+
+        let mut note_statuses = BTreeMap::new();
+        note_statuses.insert(broken.id.clone(), ProjectStatus::Development);
+
+        let projects = vec![broken];
+        let items = find_attention(&projects, &AttentionThresholds::default(), &note_statuses, now);
+        let messages: Vec<&str> = items[0].reasons.iter().map(|r| r.message.as_str()).collect();
+
+        assert_eq!(messages, vec![
+            "dependency missing-id does not exist",
+            "time tracking is still running although Completed",
+            "overview note says Development but metadata says Completed",
+        ]);
+    }
+
+    #[test]
+    fn test_thresholds_from_yaml() {
+        let thresholds: AttentionThresholds = serde_yaml::from_str("stale_days:\n  idea: 30\n").unwrap();
+        assert_eq!(thresholds.stale_days_for(&ProjectStatus::Idea), Some(30));
+        assert_eq!(thresholds.stale_days_for(&ProjectStatus::Development), None);
+        assert_eq!(thresholds.max_days_in(&ProjectStatus::Testing), Some(30));
+    }
+}
@@ -0,0 +1,95 @@
+// Kanban-style grouping of projects by workflow status
+
+use crate::core::{get_status_progression, Priority, Project, ProjectStatus};
+
+/// Active projects without an update or note edit for longer than this are
+/// flagged as stale.
+pub const STALE_AFTER_DAYS: i64 = 7;
+
+pub struct BoardColumn<'a> {
+    pub status: ProjectStatus,
+    pub projects: Vec<&'a Project>,
+}
+
+/// Workflow columns from the configured `status_values`, in order. Unknown
+/// and duplicate entries are skipped; an empty result falls back to the
+/// standard progression.
+pub fn workflow_columns(status_values: &[String]) -> Vec<ProjectStatus> {
+    let mut columns: Vec<ProjectStatus> = Vec::new();
+    for value in status_values {
+        if let Ok(status) = value.parse::<ProjectStatus>() {
+            if !columns.contains(&status) {
+                columns.push(status);
+            }
+        }
+    }
+
+    if columns.is_empty() {
+        get_status_progression()
+    } else {
+        columns
+    }
+}
+
+/// Group projects into the given columns, highest priority and most recently
+/// updated first. Projects whose status is not a column are left out.
+pub fn build_board<'a>(columns: &[ProjectStatus], projects: &'a [Project]) -> Vec<BoardColumn<'a>> {
+    columns.iter()
+        .map(|status| {
+            let mut cards: Vec<&Project> = projects.iter()
+                .filter(|p| p.status == *status)
+                .collect();
+            cards.sort_by(|a, b| {
+                priority_rank(&b.priority).cmp(&priority_rank(&a.priority))
+                    .then(b.updated.cmp(&a.updated))
+            });
+            BoardColumn { status: status.clone(), projects: cards }
+        })
+        .collect()
+}
+
+pub fn is_stale(project: &Project) -> bool {
+    project.is_active() && project.days_since_activity() > STALE_AFTER_DAYS
+}
+
+fn priority_rank(priority: &Priority) -> u8 {
+    match priority {
+        Priority::High => 3,
+        Priority::Medium => 2,
+        Priority::Low => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    #[test]
+    fn test_workflow_columns() {
+        let values: Vec<String> = ["planning", "Development", "bogus", "planning", "completed"]
+            .iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            workflow_columns(&values),
+            vec![ProjectStatus::Planning, ProjectStatus::Development, ProjectStatus::Completed]
+        );
+        assert_eq!(workflow_columns(&[]), get_status_progression());
+    }
+
+    #[test]
+    fn test_build_board() {
+        let projects = vec![
+            project("low").with_priority(Priority::Low).with_status(ProjectStatus::Development),
+            project("high").with_priority(Priority::High).with_status(ProjectStatus::Development),
+            project("done").with_status(ProjectStatus::Completed),
+            project("dropped").with_status(ProjectStatus::Cancelled),
+        ];
+
+        let board = build_board(&[ProjectStatus::Development, ProjectStatus::Completed], &projects);
+        assert_eq!(board.len(), 2);
+        let names: Vec<&str> = board[0].projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["high", "low"]);
+        assert_eq!(board[1].projects.len(), 1);
+        assert!(!is_stale(board[0].projects[0]));
+    }
+}
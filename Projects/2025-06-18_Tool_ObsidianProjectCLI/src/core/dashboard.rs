@@ -0,0 +1,164 @@
+// Generated sections of the `Projects/_Dashboard.md` index note
+
+use chrono::{DateTime, Duration, Utc};
+use std::path::Path;
+
+use crate::core::{is_stale, Priority, Project, ProjectStats, ProjectStatus};
+use crate::utils::date::format_duration_hm;
+use crate::utils::markdown::overview_wikilink;
+
+/// Completed projects stay in the "Recently Completed" section this long.
+pub const RECENT_COMPLETION_DAYS: i64 = 30;
+
+/// Managed regions of the dashboard note as `(region name, markdown body)`
+/// pairs, in the order they appear in a freshly created note.
+pub fn render_dashboard(projects: &[Project], vault_path: &Path, now: DateTime<Utc>) -> Vec<(&'static str, String)> {
+    let stats = ProjectStats::compute(projects);
+
+    let mut high_priority: Vec<&Project> = projects.iter()
+        .filter(|p| p.priority == Priority::High && !is_finished(p))
+        .collect();
+    high_priority.sort_by_key(|p| std::cmp::Reverse(p.updated));
+
+    let mut active: Vec<&Project> = projects.iter().filter(|p| p.is_active()).collect();
+    active.sort_by_key(|p| std::cmp::Reverse(p.updated));
+
+    let mut stale: Vec<&Project> = projects.iter().filter(|p| is_stale(p)).collect();
+    stale.sort_by_key(|p| p.updated);
+
+    let cutoff = now - Duration::days(RECENT_COMPLETION_DAYS);
+    let mut completed: Vec<(&Project, DateTime<Utc>)> = projects.iter()
+        .filter(|p| p.status == ProjectStatus::Completed)
+        .filter_map(|p| p.completed_at().map(|at| (p, at)))
+        .filter(|(_, at)| *at >= cutoff)
+        .collect();
+    completed.sort_by_key(|(_, at)| std::cmp::Reverse(*at));
+
+    vec![
+        ("summary", render_summary(&stats)),
+        ("high-priority", render_section(
+            "🔴 High Priority",
+            high_priority.iter().map(|p| format!("{} · {}", overview_wikilink(p, vault_path), p.status)),
+        )),
+        ("active", render_section(
+            "🚧 Active",
+            active.iter().map(|p| format!(
+                "{} · {} · {} priority",
+                overview_wikilink(p, vault_path),
+                p.status,
+                p.priority
+            )),
+        )),
+        ("stale", render_section(
+            "⚠️ Stale",
+            stale.iter().map(|p| format!(
+                "{} · {} · last updated {}",
+                overview_wikilink(p, vault_path),
+                p.status,
+                p.updated.format("%Y-%m-%d")
+            )),
+        )),
+        ("completed", render_section(
+            "✅ Recently Completed",
+            completed.iter().map(|(p, at)| format!(
+                "{} · completed {}",
+                overview_wikilink(p, vault_path),
+                at.format("%Y-%m-%d")
+            )),
+        )),
+    ]
+}
+
+fn render_summary(stats: &ProjectStats) -> String {
+    let mut out = String::from("## 📊 Summary\n\n| Metric | Value |\n| --- | --- |\n");
+    out.push_str(&format!("| Total Projects | {} |\n", stats.total));
+    out.push_str(&format!("| Active Projects | {} |\n", stats.active));
+    out.push_str(&format!("| Completed Projects | {} |\n", stats.completed));
+    out.push_str(&format!("| Completion Rate | {:.1}% |\n", stats.completion_rate()));
+    out.push_str(&format!("| Stale Projects | {} |\n", stats.stale));
+    out.push_str(&format!("| Average Project Age | {:.1} days |\n", stats.average_age_days));
+    out.push_str(&format!("| Time Logged | {} |\n", format_duration_hm(&stats.total_time)));
+
+    let breakdown = |counts: Vec<String>| if counts.is_empty() { "_None_".to_string() } else { counts.join(" · ") };
+    out.push_str(&format!(
+        "\n**By status:** {}\n",
+        breakdown(stats.by_status.iter().map(|(status, count)| format!("{} {}", status, count)).collect())
+    ));
+    out.push_str(&format!(
+        "**By type:** {}\n",
+        breakdown(stats.by_type.iter().map(|(name, count)| format!("{} {}", name, count)).collect())
+    ));
+    out.push_str(&format!(
+        "**By priority:** {}",
+        breakdown(stats.by_priority.iter().map(|(priority, count)| format!("{} {}", priority, count)).collect())
+    ));
+
+    out
+}
+
+fn render_section(title: &str, lines: impl Iterator<Item = String>) -> String {
+    let items: Vec<String> = lines.map(|line| format!("- {}", line)).collect();
+    let body = if items.is_empty() { "_None_".to_string() } else { items.join("\n") };
+    format!("## {}\n\n{}", title, body)
+}
+
+fn is_finished(project: &Project) -> bool {
+    matches!(
+        project.status,
+        ProjectStatus::Completed | ProjectStatus::Archived | ProjectStatus::Cancelled
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    fn region<'a>(regions: &'a [(&str, String)], name: &str) -> &'a str {
+        &regions.iter().find(|(region, _)| *region == name).unwrap().1
+    }
+
+    #[test]
+    fn test_render_dashboard() {
+        let project = |name: &str, priority: Priority, status: ProjectStatus| {
+            project(name).with_priority(priority).with_status(status).with_dir(Path::new("/vault/Projects").join(name))
+        };
+
+        let mut stale = project("stale", Priority::Medium, ProjectStatus::Development);
+        stale.updated = Utc::now() - Duration::days(20);
+        let mut old_done = project("old-done", Priority::Low, ProjectStatus::Completed);
+        old_done.status_history.last_mut().unwrap().timestamp = Utc::now() - Duration::days(90);
+
+        let projects = vec![
+            project("urgent", Priority::High, ProjectStatus::Planning),
+            project("done", Priority::High, ProjectStatus::Completed),
+            stale,
+            old_done,
+        ];
+
+        let regions = render_dashboard(&projects, Path::new("/vault"), Utc::now());
+        let names: Vec<&str> = regions.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["summary", "high-priority", "active", "stale", "completed"]);
+
+        assert!(region(&regions, "summary").contains("| Total Projects | 4 |"));
+        assert!(region(&regions, "summary").contains("**By status:** Planning 1 · Development 1 · Completed 2"));
+
+        let high = region(&regions, "high-priority");
+        assert!(high.contains("[[Projects/urgent/00_PROJECT-OVERVIEW|urgent]] · Planning"));
+        assert!(!high.contains("done"));
+
+        assert!(region(&regions, "active").contains("|stale]]"));
+        assert!(region(&regions, "stale").contains("|stale]] · Development · last updated"));
+
+        let completed = region(&regions, "completed");
+        assert!(completed.contains("|done]] · completed"));
+        assert!(!completed.contains("old-done"));
+    }
+
+    #[test]
+    fn test_empty_sections() {
+        let regions = render_dashboard(&[], Path::new("/vault"), Utc::now());
+        assert!(region(&regions, "stale").ends_with("_None_"));
+        assert!(region(&regions, "summary").contains("**By type:** _None_"));
+    }
+}
@@ -0,0 +1,320 @@
+// Vault health checks behind `opj doctor`
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::core::{find_by_id, template_name, Priority, Project, ProjectStatus, ProjectType};
+
+pub const METADATA_FILE: &str = ".project-meta.yaml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IssueKind {
+    InvalidMetadata,
+    DuplicateId,
+    DanglingRelation,
+    StatusHistory,
+    OverviewMismatch,
+    MissingTemplate,
+}
+
+impl std::fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            IssueKind::InvalidMetadata => write!(f, "invalid-metadata"),
+            IssueKind::DuplicateId => write!(f, "duplicate-id"),
+            IssueKind::DanglingRelation => write!(f, "dangling-relation"),
+            IssueKind::StatusHistory => write!(f, "status-history"),
+            IssueKind::OverviewMismatch => write!(f, "overview-mismatch"),
+            IssueKind::MissingTemplate => write!(f, "missing-template"),
+        }
+    }
+}
+
+/// A repair that only touches the project the issue was found in.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fix {
+    /// Give the project a fresh ID
+    NewId,
+    /// Remove a reference to a project that does not exist
+    DropRelation { id: String },
+    /// Append the current status to the status history
+    RecordStatus,
+    /// Rewrite an overview frontmatter key to match the metadata
+    SetOverview { key: &'static str, value: String },
+}
+
+impl Fix {
+    pub fn describe(&self) -> String {
+        match self {
+            Fix::NewId => "assign a new ID".to_string(),
+            Fix::DropRelation { id } => format!("remove the reference to {}", id),
+            Fix::RecordStatus => "record the current status in the history".to_string(),
+            Fix::SetOverview { key, value } => format!("set '{}: {}' in the overview note", key, value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// File or directory the problem is in
+    pub path: PathBuf,
+    /// Directory of the affected project, if any
+    pub project_dir: Option<PathBuf>,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+/// `status` and `priority` as written in an overview note's frontmatter.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OverviewFields {
+    pub status: Option<ProjectStatus>,
+    pub priority: Option<Priority>,
+}
+
+/// Take over a status or priority changed in the overview note, e.g. from
+/// Obsidian. Returns a description of each change made to the project.
+pub fn sync_from_overview(project: &mut Project, overview: &OverviewFields) -> Vec<String> {
+    let mut changes = Vec::new();
+    if let Some(status) = overview.status.clone().filter(|status| *status != project.status) {
+        changes.push(format!("status {} → {}", project.status, status));
+        project.update_status(status, Some("Synced from overview note".to_string()));
+    }
+    if let Some(priority) = overview.priority.clone().filter(|priority| *priority != project.priority) {
+        changes.push(format!("priority {} → {}", project.priority, priority));
+        project.update_priority(priority);
+    }
+    changes
+}
+
+/// Everything the checks look at, gathered from the vault by the caller.
+pub struct VaultState<'a> {
+    pub projects: &'a [Project],
+    /// Project directories that could not be loaded, with the error
+    pub failures: Vec<(PathBuf, String)>,
+    /// Overview frontmatter of projects that have a metadata file, by directory
+    pub overviews: BTreeMap<PathBuf, OverviewFields>,
+    pub templates_path: PathBuf,
+    pub default_type: ProjectType,
+}
+
+pub fn diagnose(state: &VaultState) -> Vec<Issue> {
+    let mut issues: Vec<Issue> = state.failures.iter()
+        .map(|(dir, error)| {
+            let metadata_path = dir.join(METADATA_FILE);
+            Issue {
+                kind: IssueKind::InvalidMetadata,
+                path: if metadata_path.exists() { metadata_path } else { dir.clone() },
+                project_dir: Some(dir.clone()),
+                message: format!("project is skipped: {}", error),
+                fix: None,
+            }
+        })
+        .collect();
+
+    issues.extend(duplicate_ids(state.projects));
+
+    for project in state.projects {
+        let Some(dir) = &project.path else { continue };
+        let issue = |kind, path: PathBuf, message: String, fix| Issue {
+            kind,
+            path,
+            project_dir: Some(dir.clone()),
+            message,
+            fix: Some(fix),
+        };
+
+        // Projects without metadata are derived from their folder on every scan
+        if !dir.join(METADATA_FILE).exists() {
+            continue;
+        }
+        let metadata_path = dir.join(METADATA_FILE);
+
+        let relations = project.parent.iter().map(|id| ("parent", id))
+            .chain(project.depends_on.iter().map(|id| ("dependency", id)))
+            .chain(project.related.iter().map(|id| ("related project", id)));
+        for (relation, id) in relations {
+            if find_by_id(state.projects, id).is_none() {
+                issues.push(issue(
+                    IssueKind::DanglingRelation,
+                    metadata_path.clone(),
+                    format!("{} {} does not exist", relation, id),
+                    Fix::DropRelation { id: id.clone() },
+                ));
+            }
+        }
+
+        if project.status_history.last().is_none_or(|entry| entry.status != project.status) {
+            issues.push(issue(
+                IssueKind::StatusHistory,
+                metadata_path.clone(),
+                format!("status is {} but the history does not end there", project.status),
+                Fix::RecordStatus,
+            ));
+        }
+
+        if let Some(overview) = state.overviews.get(dir) {
+            let overview_path = dir.join(crate::utils::markdown::OVERVIEW_FILE);
+            if let Some(status) = overview.status.as_ref().filter(|status| **status != project.status) {
+                issues.push(issue(
+                    IssueKind::OverviewMismatch,
+                    overview_path.clone(),
+                    format!("overview says status {} but metadata says {}", status, project.status),
+                    Fix::SetOverview { key: "status", value: project.status.to_string().to_lowercase() },
+                ));
+            }
+            if let Some(priority) = overview.priority.as_ref().filter(|priority| **priority != project.priority) {
+                issues.push(issue(
+                    IssueKind::OverviewMismatch,
+                    overview_path,
+                    format!("overview says priority {} but metadata says {}", priority, project.priority),
+                    Fix::SetOverview { key: "priority", value: project.priority.to_string().to_lowercase() },
+                ));
+            }
+        }
+    }
+
+    let mut types: Vec<&ProjectType> = vec![&state.default_type];
+    types.extend(state.projects.iter().map(|project| &project.project_type));
+    let templates: BTreeSet<&str> = types.into_iter().filter_map(template_name).collect();
+    for template in templates {
+        let path = state.templates_path.join(template);
+        if !path.is_dir() {
+            issues.push(Issue {
+                kind: IssueKind::MissingTemplate,
+                path,
+                project_dir: None,
+                message: format!("template {} is missing; new projects get a basic structure instead", template),
+                fix: None,
+            });
+        }
+    }
+
+    issues.sort_by(|a, b| a.kind.cmp(&b.kind).then(a.path.cmp(&b.path)));
+    issues
+}
+
+/// Projects sharing an ID with an older one. The oldest keeps it.
+fn duplicate_ids(projects: &[Project]) -> Vec<Issue> {
+    let mut by_id: BTreeMap<&str, Vec<&Project>> = BTreeMap::new();
+    for project in projects {
+        by_id.entry(&project.id).or_default().push(project);
+    }
+
+    let mut issues = Vec::new();
+    for (id, mut group) in by_id.into_iter().filter(|(_, group)| group.len() > 1) {
+        group.sort_by(|a, b| a.created.cmp(&b.created).then(a.path.cmp(&b.path)));
+        let keeper = display_dir(group[0].path.as_deref());
+        for duplicate in &group[1..] {
+            let dir = duplicate.path.clone();
+            issues.push(Issue {
+                kind: IssueKind::DuplicateId,
+                path: dir.as_ref().map(|dir| dir.join(METADATA_FILE)).unwrap_or_default(),
+                project_dir: dir,
+                message: format!("ID {} is also used by {}", id, keeper),
+                fix: Some(Fix::NewId),
+            });
+        }
+    }
+    issues
+}
+
+fn display_dir(path: Option<&Path>) -> String {
+    path.and_then(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "another project".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::ProjectFixture;
+    use tempfile::tempdir;
+
+    fn managed(root: &Path, dir: &str) -> Project {
+        let path = root.join(dir);
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join(METADATA_FILE), "").unwrap();
+
+        crate::core::fixture::project(dir).with_dir(path)
+    }
+
+    fn state<'a>(projects: &'a [Project], root: &Path) -> VaultState<'a> {
+        VaultState {
+            projects,
+            failures: Vec::new(),
+            overviews: BTreeMap::new(),
+            templates_path: root.join("_TEMPLATES"),
+            default_type: ProjectType::Tool,
+        }
+    }
+
+    #[test]
+    fn test_healthy_vault() {
+        let root = tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("_TEMPLATES/Tool-Project")).unwrap();
+        let projects = vec![managed(root.path(), "a"), managed(root.path(), "b")];
+
+        assert!(diagnose(&state(&projects, root.path())).is_empty());
+    }
+
+    #[test]
+    fn test_metadata_issues() {
+        let root = tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("_TEMPLATES/Tool-Project")).unwrap();
+
+        let original = managed(root.path(), "a");
+        let mut copy = managed(root.path(), "b");
+        copy.id = original.id.clone();
+        copy.created = original.created + chrono::Duration::days(1);
+        copy.depends_on.push("gone".to_string());
+        copy.status = ProjectStatus::Testing;
+
+        let projects = vec![copy, original];
+        let mut vault = state(&projects, root.path());
+        vault.failures.push((root.path().join("broken"), "invalid YAML at line 3".to_string()));
+        vault.overviews.insert(root.path().join("a"), OverviewFields {
+            status: Some(ProjectStatus::Idea),
+            priority: Some(Priority::High),
+        });
+
+        let issues = diagnose(&vault);
+        let summary: Vec<(IssueKind, Option<Fix>)> = issues.iter().map(|i| (i.kind, i.fix.clone())).collect();
+        assert_eq!(summary, vec![
+            (IssueKind::InvalidMetadata, None),
+            (IssueKind::DuplicateId, Some(Fix::NewId)),
+            (IssueKind::DanglingRelation, Some(Fix::DropRelation { id: "gone".to_string() })),
+            (IssueKind::StatusHistory, Some(Fix::RecordStatus)),
+            (IssueKind::OverviewMismatch, Some(Fix::SetOverview { key: "priority", value: "medium".to_string() })),
+        ]);
+        assert_eq!(issues[1].path, root.path().join("b").join(METADATA_FILE));
+        assert_eq!(issues[1].message, format!("ID {} is also used by a", projects[1].id));
+    }
+
+    #[test]
+    fn test_sync_from_overview() {
+        let root = tempdir().unwrap();
+        let mut project = managed(root.path(), "a");
+        let overview = OverviewFields { status: Some(ProjectStatus::Planning), priority: Some(Priority::Medium) };
+
+        assert_eq!(sync_from_overview(&mut project, &overview), vec!["status Idea → Planning"]);
+        assert_eq!(project.status, ProjectStatus::Planning);
+        assert_eq!(project.status_history.last().unwrap().status, ProjectStatus::Planning);
+        assert!(sync_from_overview(&mut project, &overview).is_empty());
+    }
+
+    #[test]
+    fn test_missing_templates() {
+        let root = tempdir().unwrap();
+        let mut article = managed(root.path(), "post");
+        article.project_type = ProjectType::Content;
+        let projects = vec![article];
+
+        let names: Vec<String> = diagnose(&state(&projects, root.path())).iter()
+            .map(|issue| issue.path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["Content-Project", "Tool-Project"]);
+    }
+}
@@ -0,0 +1,163 @@
+// Project relation graph rendering (Graphviz DOT and Mermaid)
+
+use std::collections::HashMap;
+
+use crate::core::{Project, ProjectStatus};
+
+/// Fill color used for a project node of the given status.
+pub fn status_color(status: &ProjectStatus) -> &'static str {
+    match status {
+        ProjectStatus::Idea => "#e0e0e0",
+        ProjectStatus::Planning => "#90caf9",
+        ProjectStatus::Development => "#ffcc80",
+        ProjectStatus::Testing => "#ce93d8",
+        ProjectStatus::Completed => "#a5d6a7",
+        ProjectStatus::Archived => "#f5f5f5",
+        ProjectStatus::Cancelled => "#ef9a9a",
+    }
+}
+
+fn status_class(status: &ProjectStatus) -> String {
+    status.to_string().to_lowercase()
+}
+
+/// Edges between the given projects as `(from, to, is_dependency)` triples
+/// of node indices.
+/// Links to projects outside the slice are dropped, and symmetric "related"
+/// links are only reported once.
+fn edges(projects: &[Project]) -> Vec<(usize, usize, bool)> {
+    let index: HashMap<&str, usize> = projects.iter()
+        .enumerate()
+        .map(|(i, p)| (p.id.as_str(), i))
+        .collect();
+
+    let mut edges = Vec::new();
+    for (from, project) in projects.iter().enumerate() {
+        for id in &project.depends_on {
+            if let Some(&to) = index.get(id.as_str()) {
+                edges.push((from, to, true));
+            }
+        }
+        for id in &project.related {
+            if let Some(&to) = index.get(id.as_str()) {
+                let seen = edges.iter().any(|&(a, b, dep)| !dep && a == to && b == from);
+                if !seen {
+                    edges.push((from, to, false));
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+/// Render the graph in Graphviz DOT. Dependencies point from the dependent
+/// project to the project it depends on; related projects are joined by a
+/// dashed, undirected edge.
+pub fn render_dot(projects: &[Project]) -> String {
+    let mut out = String::from("digraph projects {\n");
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+
+    for (i, project) in projects.iter().enumerate() {
+        out.push_str(&format!(
+            "    n{} [label=\"{}\\n{}\", fillcolor=\"{}\"];\n",
+            i,
+            escape_dot(&project.name),
+            project.status,
+            status_color(&project.status)
+        ));
+    }
+
+    for (from, to, depends) in edges(projects) {
+        if depends {
+            out.push_str(&format!("    n{} -> n{};\n", from, to));
+        } else {
+            out.push_str(&format!("    n{} -> n{} [style=dashed, dir=none];\n", from, to));
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Render the graph as a Mermaid flowchart (without the surrounding code fence).
+pub fn render_mermaid(projects: &[Project]) -> String {
+    let mut out = String::from("graph LR\n");
+
+    for (i, project) in projects.iter().enumerate() {
+        out.push_str(&format!("    n{}[\"{}\"]\n", i, escape_mermaid(&project.name)));
+    }
+
+    for (from, to, depends) in edges(projects) {
+        let arrow = if depends { "-->" } else { "-.-" };
+        out.push_str(&format!("    n{} {} n{}\n", from, arrow, to));
+    }
+
+    let mut classes: Vec<(&ProjectStatus, Vec<String>)> = Vec::new();
+    for (i, project) in projects.iter().enumerate() {
+        match classes.iter_mut().find(|(status, _)| **status == project.status) {
+            Some((_, nodes)) => nodes.push(format!("n{}", i)),
+            None => classes.push((&project.status, vec![format!("n{}", i)])),
+        }
+    }
+
+    for (status, nodes) in classes {
+        out.push_str(&format!(
+            "    classDef {} fill:{},stroke:#555\n",
+            status_class(status),
+            status_color(status)
+        ));
+        out.push_str(&format!("    class {} {}\n", nodes.join(","), status_class(status)));
+    }
+
+    out
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::project;
+    use crate::core::RelationKind;
+
+    fn sample() -> Vec<Project> {
+        let mut web = project("web");
+        let mut api = project("api");
+        let mut docs = project("docs \"v2\"");
+        web.add_relation(RelationKind::DependsOn, "api");
+        web.add_relation(RelationKind::DependsOn, "missing");
+        web.add_relation(RelationKind::Related, "docs \"v2\"");
+        api.update_status(ProjectStatus::Completed, None);
+        docs.add_relation(RelationKind::Related, "web");
+        vec![web, api, docs]
+    }
+
+    #[test]
+    fn test_render_dot() {
+        let dot = render_dot(&sample());
+        assert!(dot.starts_with("digraph projects {"));
+        assert!(dot.contains("n1 [label=\"api\\nCompleted\", fillcolor=\"#a5d6a7\"];"));
+        assert!(dot.contains("n2 [label=\"docs \\\"v2\\\"\\nIdea\""));
+        assert!(dot.contains("    n0 -> n1;\n"));
+        assert_eq!(dot.matches("dir=none").count(), 1);
+    }
+
+    #[test]
+    fn test_render_mermaid() {
+        let mermaid = render_mermaid(&sample());
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("n2[\"docs #quot;v2#quot;\"]"));
+        assert!(mermaid.contains("    n0 --> n1\n"));
+        assert_eq!(mermaid.matches("-.-").count(), 1);
+        assert!(mermaid.contains("class n0,n2 idea\n"));
+        assert!(mermaid.contains("classDef completed fill:#a5d6a7"));
+    }
+}
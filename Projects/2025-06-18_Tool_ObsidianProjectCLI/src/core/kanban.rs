@@ -0,0 +1,168 @@
+// Obsidian Kanban plugin board notes
+
+use regex::Regex;
+use std::path::Path;
+
+use crate::core::{BoardColumn, Project, ProjectStatus};
+use crate::utils::markdown::{overview_wikilink, OVERVIEW_FILE};
+
+const KANBAN_FRONTMATTER: &str = "---\n\nkanban-plugin: basic\n\n---\n";
+const KANBAN_SETTINGS: &str = "%% kanban:settings\n```\n{\"kanban-plugin\":\"basic\"}\n```\n%%\n";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanbanCard {
+    pub text: String,
+    pub checked: bool,
+    /// Target of the first wikilink on the card, if any
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanbanLane {
+    pub title: String,
+    pub cards: Vec<KanbanCard>,
+}
+
+/// Render a board note in the Kanban plugin's markdown format: one lane per
+/// column, one card per project linking to its overview note.
+pub fn render_kanban(board: &[BoardColumn], vault_path: &Path) -> String {
+    let mut out = String::from(KANBAN_FRONTMATTER);
+
+    for column in board {
+        out.push_str(&format!("\n## {}\n\n", column.status));
+        let checked = matches!(column.status, ProjectStatus::Completed | ProjectStatus::Archived);
+        for project in &column.projects {
+            out.push_str(&format!(
+                "- [{}] {}\n",
+                if checked { "x" } else { " " },
+                overview_wikilink(project, vault_path)
+            ));
+        }
+    }
+
+    out.push_str("\n\n");
+    out.push_str(KANBAN_SETTINGS);
+    out
+}
+
+/// Parse the lanes of a Kanban plugin board note. Archived cards (after the
+/// `***` separator) and the plugin settings block are ignored.
+pub fn parse_kanban(content: &str) -> Vec<KanbanLane> {
+    let card_re = Regex::new(r"^\s*[-*]\s+\[([ xX])\]\s+(.*)$").unwrap();
+    let link_re = Regex::new(r"\[\[([^\]|#]+)(?:#[^\]|]*)?(?:\|[^\]]*)?\]\]").unwrap();
+
+    let mut lanes: Vec<KanbanLane> = Vec::new();
+    let mut in_frontmatter = false;
+
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if trimmed == "---" && (i == 0 || in_frontmatter) {
+            in_frontmatter = !in_frontmatter;
+            continue;
+        }
+        if in_frontmatter {
+            continue;
+        }
+        if trimmed == "***" || trimmed.starts_with("%% kanban:settings") {
+            break;
+        }
+
+        if let Some(title) = trimmed.strip_prefix("## ") {
+            lanes.push(KanbanLane { title: title.trim().to_string(), cards: Vec::new() });
+            continue;
+        }
+
+        let (Some(lane), Some(caps)) = (lanes.last_mut(), card_re.captures(line)) else {
+            continue;
+        };
+
+        let text = caps[2].trim().to_string();
+        let link = link_re.captures(&text).map(|link| link[1].trim().to_string());
+        lane.cards.push(KanbanCard {
+            checked: &caps[1] != " ",
+            link,
+            text,
+        });
+    }
+
+    lanes
+}
+
+/// Find the project a card refers to: by the overview note it links to, then
+/// by the linked note or card text matching a project name.
+pub fn resolve_card<'a>(card: &KanbanCard, projects: &'a [Project]) -> Option<&'a Project> {
+    if let Some(link) = &card.link {
+        let overview_stem = OVERVIEW_FILE.trim_end_matches(".md");
+        let link_path = link.trim_end_matches(".md");
+
+        // Match on the project directory name so links keep resolving when
+        // the board note or the projects folder moves within the vault
+        if let Some(project_dir) = link_path.strip_suffix(overview_stem) {
+            let dir_name = project_dir.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+            let by_dir = projects.iter().find(|p| {
+                p.path.as_ref()
+                    .and_then(|path| path.file_name())
+                    .is_some_and(|name| name.to_string_lossy() == dir_name)
+            });
+            if by_dir.is_some() {
+                return by_dir;
+            }
+        }
+
+        let note_name = link_path.rsplit('/').next().unwrap_or(link_path);
+        if let Some(project) = projects.iter().find(|p| p.name.eq_ignore_ascii_case(note_name)) {
+            return Some(project);
+        }
+    }
+
+    projects.iter().find(|p| p.name.eq_ignore_ascii_case(card.text.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::build_board;
+
+    use crate::core::fixture::{project, ProjectFixture};
+
+    #[test]
+    fn test_render_and_parse_roundtrip() {
+        let vault = Path::new("/vault");
+        let projects = vec![
+            project("Web").with_status(ProjectStatus::Development).with_dir("/vault/Projects/2025-06-18_Web-App_Web"),
+            project("Api").with_status(ProjectStatus::Completed).with_dir("/vault/Projects/2025-06-01_API_Api"),
+        ];
+        let board = build_board(&[ProjectStatus::Development, ProjectStatus::Completed], &projects);
+
+        let note = render_kanban(&board, vault);
+        assert!(note.starts_with("---\n\nkanban-plugin: basic\n\n---\n"));
+        assert!(note.contains("## Development\n\n- [ ] [[Projects/2025-06-18_Web-App_Web/00_PROJECT-OVERVIEW|Web]]\n"));
+        assert!(note.contains("## Completed\n\n- [x] [[Projects/2025-06-01_API_Api/00_PROJECT-OVERVIEW|Api]]\n"));
+
+        let lanes = parse_kanban(&note);
+        assert_eq!(lanes.len(), 2);
+        assert_eq!(lanes[0].title, "Development");
+        assert_eq!(lanes[1].cards[0].link.as_deref(), Some("Projects/2025-06-01_API_Api/00_PROJECT-OVERVIEW"));
+        assert!(lanes[1].cards[0].checked);
+
+        let resolved = resolve_card(&lanes[0].cards[0], &projects).unwrap();
+        assert_eq!(resolved.name, "Web");
+    }
+
+    #[test]
+    fn test_parse_kanban_ignores_archive_and_settings() {
+        let note = "---\n\nkanban-plugin: basic\n\n---\n\n## Testing\n\n- [ ] Api\n- [ ] [[Web|the web app]]\nNot a card\n\n***\n\n## Archive\n\n- [x] Old\n\n%% kanban:settings\n%%\n";
+        let lanes = parse_kanban(note);
+        assert_eq!(lanes.len(), 1);
+        assert_eq!(lanes[0].cards.len(), 2);
+        assert_eq!(lanes[0].cards[1].link.as_deref(), Some("Web"));
+
+        let projects = vec![
+            project("Api").with_dir("/vault/Projects/a"),
+            project("Web").with_dir("/vault/Projects/b"),
+        ];
+        assert_eq!(resolve_card(&lanes[0].cards[0], &projects).unwrap().name, "Api");
+        assert_eq!(resolve_card(&lanes[0].cards[1], &projects).unwrap().name, "Web");
+    }
+}
@@ -1,20 +1,76 @@
-// Placeholder for metadata management
-// This will be expanded in future versions
+// Reading and writing `.project-meta.yaml`
 
 use crate::core::Project;
+use crate::fs::lock::ProjectLock;
+use crate::fs::operations::{write_atomic, FileState};
+use crate::utils::error;
 use anyhow::Result;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-pub async fn save_project_metadata(project: &Project, project_path: &Path) -> Result<()> {
+static FORCE: AtomicBool = AtomicBool::new(false);
+
+/// Overwrite metadata even when it changed since it was read (`--force`).
+pub fn set_force(enabled: bool) {
+    FORCE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn force() -> bool {
+    FORCE.load(Ordering::Relaxed)
+}
+
+/// Save the project's metadata. The file is locked while it is written and
+/// replaced in one step; if Obsidian, Git or another opj changed it since the
+/// project was loaded, nothing is written unless `--force` was given.
+pub async fn save_project_metadata(project: &mut Project, project_path: &Path) -> Result<()> {
     let metadata_path = project_path.join(".project-meta.yaml");
     let metadata_content = serde_yaml::to_string(project)?;
-    tokio::fs::write(metadata_path, metadata_content).await?;
+
+    let _lock = ProjectLock::acquire(project_path).await?;
+    if FileState::of(&metadata_path) != project.metadata_state && !force() {
+        return Err(error::conflict(format!(
+            "{} changed since opj read it; nothing was written (run the command again, or use --force to overwrite)",
+            metadata_path.display()
+        )));
+    }
+
+    write_atomic(&metadata_path, &metadata_content).await?;
+    project.metadata_state = FileState::of(&metadata_path);
     Ok(())
 }
 
 pub async fn load_project_metadata(project_path: &Path) -> Result<Project> {
     let metadata_path = project_path.join(".project-meta.yaml");
+    let metadata_state = FileState::of(&metadata_path);
     let content = tokio::fs::read_to_string(metadata_path).await?;
-    let project: Project = serde_yaml::from_str(&content)?;
+    let mut project: Project = serde_yaml::from_str(&content)?;
+    project.metadata_state = metadata_state;
     Ok(project)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_save_rejects_changes_made_since_loading() {
+        let dir = tempdir().unwrap();
+        let mut project = crate::core::fixture::project("App");
+        save_project_metadata(&mut project, dir.path()).await.unwrap();
+
+        // Saving again works: the state was refreshed by the first save
+        project.description = Some("first".to_string());
+        save_project_metadata(&mut project, dir.path()).await.unwrap();
+
+        let mut stale = load_project_metadata(dir.path()).await.unwrap();
+        project.description = Some("from another process, longer".to_string());
+        save_project_metadata(&mut project, dir.path()).await.unwrap();
+
+        stale.description = Some("stale".to_string());
+        assert!(save_project_metadata(&mut stale, dir.path()).await.is_err());
+        let saved = load_project_metadata(dir.path()).await.unwrap();
+        assert_eq!(saved.description.as_deref(), Some("from another process, longer"));
+        assert!(!dir.path().join(crate::fs::lock::LOCK_FILE).exists());
+    }
+}
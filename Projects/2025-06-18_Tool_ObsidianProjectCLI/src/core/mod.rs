@@ -2,8 +2,36 @@ pub mod project;
 pub mod template;
 pub mod metadata;
 pub mod status;
+pub mod task;
+pub mod time;
+pub mod relations;
+pub mod graph;
+pub mod board;
+pub mod kanban;
+pub mod stats;
+pub mod dashboard;
+pub mod report;
+pub mod query;
+pub mod trends;
+pub mod attention;
+pub mod doctor;
+pub mod archive;
 
 pub use project::*;
 pub use template::*;
 pub use metadata::*;
 pub use status::*;
+pub use task::*;
+pub use time::*;
+pub use relations::*;
+pub use graph::*;
+pub use board::*;
+pub use kanban::*;
+pub use stats::*;
+pub use dashboard::*;
+pub use report::*;
+pub use query::*;
+pub use trends::*;
+pub use attention::*;
+pub use doctor::*;
+pub use archive::*;
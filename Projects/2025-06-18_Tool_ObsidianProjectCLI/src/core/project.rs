@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use std::path::PathBuf;
 use uuid::Uuid;
 
+use crate::core::{RelationKind, TimeEntry};
+use crate::fs::operations::FileState;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Project {
     pub id: String,
@@ -16,8 +19,28 @@ pub struct Project {
     pub tags: Vec<String>,
     pub technologies: Vec<String>,
     pub status_history: Vec<StatusEntry>,
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntry>,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub related: Vec<String>,
+    #[serde(default)]
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+    /// Vault-relative folder the project was archived from
+    #[serde(default)]
+    pub archived_from: Option<String>,
     #[serde(skip)]
     pub path: Option<PathBuf>,
+    /// Modification time of the most recently edited note in the project
+    #[serde(skip)]
+    pub last_edited: Option<DateTime<Utc>>,
+    /// The metadata file as it was when the project was loaded, so a save
+    /// can tell whether something else changed it in the meantime
+    #[serde(skip)]
+    pub metadata_state: Option<FileState>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -104,7 +127,15 @@ impl Project {
             tags,
             technologies: Vec::new(),
             status_history,
+            time_entries: Vec::new(),
+            depends_on: Vec::new(),
+            related: Vec::new(),
+            parent: None,
+            due: None,
+            archived_from: None,
             path: None,
+            last_edited: None,
+            metadata_state: None,
         }
     }
 
@@ -123,6 +154,15 @@ impl Project {
         self.tags.push(format!("status-{:?}", self.status).to_lowercase());
     }
 
+    pub fn update_priority(&mut self, priority: Priority) {
+        self.priority = priority;
+        self.updated = Utc::now();
+
+        // Update priority tag
+        self.tags.retain(|tag| !tag.starts_with("priority-"));
+        self.tags.push(format!("priority-{:?}", self.priority).to_lowercase());
+    }
+
     pub fn add_technology(&mut self, tech: String) {
         if !self.technologies.contains(&tech) {
             self.technologies.push(tech.clone());
@@ -159,6 +199,13 @@ impl Project {
         )
     }
 
+    /// When the project last entered the Completed status, if it ever did.
+    pub fn completed_at(&self) -> Option<DateTime<Utc>> {
+        self.status_history.iter()
+            .rfind(|entry| entry.status == ProjectStatus::Completed)
+            .map(|entry| entry.timestamp)
+    }
+
     pub fn days_since_creation(&self) -> i64 {
         let now = Utc::now();
         now.signed_duration_since(self.created).num_days()
@@ -168,6 +215,161 @@ impl Project {
         let now = Utc::now();
         now.signed_duration_since(self.updated).num_days()
     }
+
+    /// Latest of the metadata update and the last note edit.
+    pub fn last_activity(&self) -> DateTime<Utc> {
+        self.last_edited.map_or(self.updated, |edited| edited.max(self.updated))
+    }
+
+    pub fn days_since_activity(&self) -> i64 {
+        Utc::now().signed_duration_since(self.last_activity()).num_days()
+    }
+
+    /// When the project entered its current status.
+    pub fn status_since(&self) -> DateTime<Utc> {
+        self.status_history.iter()
+            .rfind(|entry| entry.status == self.status)
+            .map_or(self.created, |entry| entry.timestamp)
+    }
+
+    pub fn active_time_entry(&self) -> Option<&TimeEntry> {
+        self.time_entries.iter().find(|entry| entry.is_running())
+    }
+
+    pub fn start_time_tracking(&mut self, note: Option<String>) -> anyhow::Result<()> {
+        if self.active_time_entry().is_some() {
+            return Err(anyhow::anyhow!("Time tracking is already running for '{}'", self.name));
+        }
+
+        self.time_entries.push(TimeEntry {
+            start: Utc::now(),
+            end: None,
+            note,
+        });
+
+        Ok(())
+    }
+
+    /// Stop the running session and return its duration, if one was running.
+    pub fn stop_time_tracking(&mut self, note: Option<String>) -> Option<Duration> {
+        let now = Utc::now();
+        let entry = self.time_entries.iter_mut().find(|entry| entry.is_running())?;
+
+        entry.end = Some(now);
+        if note.is_some() {
+            entry.note = note;
+        }
+        self.updated = now;
+
+        Some(entry.duration())
+    }
+
+    /// Record a manual session of the given length ending now. Fails when
+    /// the session would have started before any representable date.
+    pub fn add_time_entry(&mut self, duration: Duration, note: Option<String>) -> anyhow::Result<()> {
+        let now = Utc::now();
+        let start = now.checked_sub_signed(duration).ok_or_else(|| crate::utils::error::invalid_argument(format!(
+            "Duration is too long: {}",
+            crate::utils::date::format_duration_hm(&duration)
+        )))?;
+        self.time_entries.push(TimeEntry {
+            start,
+            end: Some(now),
+            note,
+        });
+        self.updated = now;
+        Ok(())
+    }
+
+    pub fn total_time_logged(&self) -> Duration {
+        self.time_entries.iter()
+            .fold(Duration::zero(), |total, entry| total + entry.duration())
+    }
+
+    pub fn add_relation(&mut self, kind: RelationKind, other_id: &str) -> bool {
+        let list = match kind {
+            RelationKind::DependsOn => &mut self.depends_on,
+            RelationKind::Related => &mut self.related,
+            RelationKind::Parent => {
+                if self.parent.as_deref() == Some(other_id) {
+                    return false;
+                }
+                self.parent = Some(other_id.to_string());
+                return true;
+            }
+        };
+
+        if list.iter().any(|id| id == other_id) {
+            return false;
+        }
+        list.push(other_id.to_string());
+        true
+    }
+
+    pub fn remove_relation(&mut self, kind: RelationKind, other_id: &str) -> bool {
+        let list = match kind {
+            RelationKind::DependsOn => &mut self.depends_on,
+            RelationKind::Related => &mut self.related,
+            RelationKind::Parent => {
+                if self.parent.as_deref() != Some(other_id) {
+                    return false;
+                }
+                self.parent = None;
+                return true;
+            }
+        };
+
+        let before = list.len();
+        list.retain(|id| id != other_id);
+        list.len() != before
+    }
+
+    /// Whether this project counts as finished for the purpose of unblocking
+    /// projects that depend on it.
+    pub fn satisfies_dependency(&self) -> bool {
+        matches!(self.status, ProjectStatus::Completed | ProjectStatus::Archived)
+    }
+}
+
+impl std::str::FromStr for ProjectStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "idea" => Ok(ProjectStatus::Idea),
+            "planning" => Ok(ProjectStatus::Planning),
+            "development" => Ok(ProjectStatus::Development),
+            "testing" => Ok(ProjectStatus::Testing),
+            "completed" => Ok(ProjectStatus::Completed),
+            "archived" => Ok(ProjectStatus::Archived),
+            "cancelled" => Ok(ProjectStatus::Cancelled),
+            _ => Err(format!("Unknown status '{}'", s.trim())),
+        }
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "high" => Ok(Priority::High),
+            "medium" => Ok(Priority::Medium),
+            "low" => Ok(Priority::Low),
+            _ => Err(format!("Unknown priority '{}'", s.trim())),
+        }
+    }
+}
+
+impl Priority {
+    /// The next priority level, wrapping from High back to Low.
+    pub fn next(&self) -> Priority {
+        match self {
+            Priority::Low => Priority::Medium,
+            Priority::Medium => Priority::High,
+            Priority::High => Priority::Low,
+        }
+    }
 }
 
 impl std::fmt::Display for ProjectType {
@@ -241,3 +443,110 @@ impl From<crate::cli::Priority> for Priority {
         }
     }
 }
+
+/// Projects for tests. `project(name)` is a medium-priority tool project
+/// whose ID is its name, so relations can be written by name; the
+/// `ProjectFixture` methods adjust it.
+#[cfg(test)]
+pub mod fixture {
+    use super::*;
+    use std::path::Path;
+
+    pub fn project(name: &str) -> Project {
+        let mut project = Project::new(
+            name.to_string(),
+            crate::cli::ProjectType::Tool,
+            crate::cli::Priority::Medium,
+            None,
+        );
+        project.id = name.to_string();
+        project
+    }
+
+    pub trait ProjectFixture {
+        fn with_type(self, project_type: impl Into<ProjectType>) -> Self;
+        fn with_priority(self, priority: impl Into<Priority>) -> Self;
+        /// Moves to the status through `update_status`, recording it in the history
+        fn with_status(self, status: ProjectStatus) -> Self;
+        fn with_tags(self, tags: &[&str]) -> Self;
+        fn with_dir(self, dir: impl AsRef<Path>) -> Self;
+        /// Created (and first recorded) at the given time
+        fn created_at(self, at: DateTime<Utc>) -> Self;
+    }
+
+    impl ProjectFixture for Project {
+        fn with_type(mut self, project_type: impl Into<ProjectType>) -> Self {
+            self.project_type = project_type.into();
+            self
+        }
+
+        fn with_priority(mut self, priority: impl Into<Priority>) -> Self {
+            self.priority = priority.into();
+            self
+        }
+
+        fn with_status(mut self, status: ProjectStatus) -> Self {
+            self.update_status(status, None);
+            self
+        }
+
+        fn with_tags(mut self, tags: &[&str]) -> Self {
+            self.tags = tags.iter().map(|tag| tag.to_string()).collect();
+            self
+        }
+
+        fn with_dir(mut self, dir: impl AsRef<Path>) -> Self {
+            self.path = Some(dir.as_ref().to_path_buf());
+            self
+        }
+
+        fn created_at(mut self, at: DateTime<Utc>) -> Self {
+            self.created = at;
+            self.status_history[0].timestamp = at;
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_tracking_session() {
+        let mut project = Project::new(
+            "Tracked".to_string(),
+            crate::cli::ProjectType::Tool,
+            crate::cli::Priority::Medium,
+            None,
+        );
+
+        project.start_time_tracking(None).unwrap();
+        assert!(project.active_time_entry().is_some());
+        assert!(project.start_time_tracking(None).is_err());
+
+        assert!(project.stop_time_tracking(Some("done".to_string())).is_some());
+        assert!(project.active_time_entry().is_none());
+        assert!(project.stop_time_tracking(None).is_none());
+
+        project.add_time_entry(Duration::minutes(90), None).unwrap();
+        assert!(project.total_time_logged() >= Duration::minutes(90));
+        assert!(project.add_time_entry(Duration::minutes(99_999_999_999_999), None).is_err());
+    }
+
+    #[test]
+    fn test_update_priority() {
+        let mut project = Project::new(
+            "Prioritized".to_string(),
+            crate::cli::ProjectType::Tool,
+            crate::cli::Priority::Low,
+            None,
+        );
+
+        project.update_priority(project.priority.next());
+        assert_eq!(project.priority, Priority::Medium);
+        assert!(project.tags.contains(&"priority-medium".to_string()));
+        assert!(!project.tags.contains(&"priority-low".to_string()));
+        assert_eq!(Priority::High.next(), Priority::Low);
+    }
+}
@@ -0,0 +1,357 @@
+// Project filter expressions shared by list, search, stats, report and board
+//
+// A query is a whitespace-separated list of terms that must all match:
+//
+//   status:development,testing priority:high tag:rust updated:<14d -status:archived
+//
+// `key:a,b` matches any of the listed values, a leading `-` negates a term,
+// `@name` expands a saved query and bare words search names, descriptions
+// and tags. Double quotes keep spaces inside a value: `name:"web app"`.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+use crate::core::{is_stale, Priority, Project, ProjectStatus, ProjectType};
+use crate::utils::error;
+
+/// Saved queries may reference each other; this bounds the expansion depth.
+const MAX_EXPANSION_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProjectQuery {
+    terms: Vec<QueryTerm>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct QueryTerm {
+    negated: bool,
+    filter: Filter,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Status(Vec<ProjectStatus>),
+    Type(Vec<String>),
+    Priority(Vec<Priority>),
+    Tag(Vec<String>),
+    Tech(Vec<String>),
+    Name(String),
+    Updated(TimeBound),
+    Created(TimeBound),
+    Is(Vec<ProjectState>),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TimeBound {
+    /// `<14d`: less than this long ago
+    Within(Duration),
+    /// `>14d`: more than this long ago
+    OlderThan(Duration),
+    /// `>2025-06-01`
+    After(NaiveDate),
+    /// `<2025-06-01`
+    Before(NaiveDate),
+    /// `2025-06-01`
+    On(NaiveDate),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ProjectState {
+    Active,
+    Stale,
+    Finished,
+}
+
+impl ProjectQuery {
+    /// Parse a query expression, expanding `@name` references from `saved`.
+    pub fn parse(input: &str, saved: &BTreeMap<String, String>) -> anyhow::Result<Self> {
+        let mut query = Self::default();
+        query.parse_into(input, saved, &mut Vec::new())
+            .map_err(|e| error::invalid_argument(e.to_string()))?;
+        Ok(query)
+    }
+
+    /// Also require this status, as `status:` would.
+    pub fn with_status(self, status: ProjectStatus) -> Self {
+        self.with(Filter::Status(vec![status]))
+    }
+
+    /// Also require this type, as `type:` would.
+    pub fn with_type(self, project_type: &ProjectType) -> Self {
+        self.with(Filter::Type(vec![normalize_type(&project_type.to_string())]))
+    }
+
+    /// Also require this priority, as `priority:` would.
+    pub fn with_priority(self, priority: Priority) -> Self {
+        self.with(Filter::Priority(vec![priority]))
+    }
+
+    fn with(mut self, filter: Filter) -> Self {
+        self.terms.push(QueryTerm { negated: false, filter });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn matches(&self, project: &Project) -> bool {
+        let now = Utc::now();
+        self.terms.iter().all(|term| term.filter.matches(project, now) != term.negated)
+    }
+
+    /// Keep only the projects matching the query.
+    pub fn apply(&self, projects: &mut Vec<Project>) {
+        if !self.is_empty() {
+            projects.retain(|p| self.matches(p));
+        }
+    }
+
+    fn parse_into(&mut self, input: &str, saved: &BTreeMap<String, String>, expanding: &mut Vec<String>) -> anyhow::Result<()> {
+        for token in tokenize(input)? {
+            if let Some(name) = token.strip_prefix('@') {
+                if expanding.iter().any(|n| n == name) || expanding.len() >= MAX_EXPANSION_DEPTH {
+                    return Err(anyhow::anyhow!("Saved query '@{}' expands recursively", name));
+                }
+                let expansion = saved.get(name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown saved query '@{}'", name))?;
+
+                expanding.push(name.to_string());
+                self.parse_into(expansion, saved, expanding)?;
+                expanding.pop();
+                continue;
+            }
+
+            let (negated, token) = match token.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest),
+                _ => (false, token.as_str()),
+            };
+            self.terms.push(QueryTerm { negated, filter: Filter::parse(token)? });
+        }
+
+        Ok(())
+    }
+}
+
+impl Filter {
+    fn parse(token: &str) -> anyhow::Result<Self> {
+        let Some((key, value)) = token.split_once(':') else {
+            return Ok(Filter::Text(token.to_lowercase()));
+        };
+        if value.is_empty() {
+            return Err(anyhow::anyhow!("Missing value in query term '{}'", token));
+        }
+
+        let values = || value.split(',').map(|v| v.trim().to_lowercase()).filter(|v| !v.is_empty());
+
+        let filter = match key.to_lowercase().as_str() {
+            "status" => Filter::Status(
+                values().map(|v| v.parse::<ProjectStatus>().map_err(anyhow::Error::msg)).collect::<anyhow::Result<_>>()?
+            ),
+            "type" => Filter::Type(values().map(|v| normalize_type(&v)).collect()),
+            "priority" => Filter::Priority(
+                values().map(|v| v.parse::<Priority>().map_err(anyhow::Error::msg)).collect::<anyhow::Result<_>>()?
+            ),
+            "tag" => Filter::Tag(values().map(|v| v.trim_start_matches('#').to_string()).collect()),
+            "tech" | "technology" => Filter::Tech(values().collect()),
+            "name" => Filter::Name(value.to_lowercase()),
+            "updated" => Filter::Updated(parse_time_bound(value)?),
+            "created" => Filter::Created(parse_time_bound(value)?),
+            "is" => Filter::Is(values().map(|v| match v.as_str() {
+                "active" => Ok(ProjectState::Active),
+                "stale" => Ok(ProjectState::Stale),
+                "finished" | "done" => Ok(ProjectState::Finished),
+                other => Err(anyhow::anyhow!("Unknown state 'is:{}' (expected active, stale or finished)", other)),
+            }).collect::<anyhow::Result<_>>()?),
+            other => return Err(anyhow::anyhow!(
+                "Unknown query key '{}' (expected status, type, priority, tag, tech, name, updated, created or is)",
+                other
+            )),
+        };
+
+        Ok(filter)
+    }
+
+    fn matches(&self, project: &Project, now: DateTime<Utc>) -> bool {
+        match self {
+            Filter::Status(statuses) => statuses.contains(&project.status),
+            Filter::Type(types) => types.contains(&normalize_type(&project.project_type.to_string())),
+            Filter::Priority(priorities) => priorities.contains(&project.priority),
+            Filter::Tag(tags) => project.tags.iter().any(|t| tags.contains(&t.to_lowercase())),
+            Filter::Tech(techs) => project.technologies.iter().any(|t| techs.contains(&t.to_lowercase())),
+            Filter::Name(name) => project.name.to_lowercase().contains(name),
+            Filter::Updated(bound) => bound.matches(project.updated, now),
+            Filter::Created(bound) => bound.matches(project.created, now),
+            Filter::Is(states) => states.iter().any(|state| match state {
+                ProjectState::Active => project.is_active(),
+                ProjectState::Stale => is_stale(project),
+                ProjectState::Finished => matches!(
+                    project.status,
+                    ProjectStatus::Completed | ProjectStatus::Archived | ProjectStatus::Cancelled
+                ),
+            }),
+            Filter::Text(text) => {
+                project.name.to_lowercase().contains(text)
+                    || project.description.as_ref().is_some_and(|d| d.to_lowercase().contains(text))
+                    || project.tags.iter().any(|t| t.to_lowercase().contains(text))
+            }
+        }
+    }
+}
+
+impl TimeBound {
+    fn matches(&self, at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        match self {
+            TimeBound::Within(age) => now.signed_duration_since(at) < *age,
+            TimeBound::OlderThan(age) => now.signed_duration_since(at) > *age,
+            TimeBound::After(date) => at.date_naive() > *date,
+            TimeBound::Before(date) => at.date_naive() < *date,
+            TimeBound::On(date) => at.date_naive() == *date,
+        }
+    }
+}
+
+fn parse_time_bound(value: &str) -> anyhow::Result<TimeBound> {
+    let (op, rest) = match value.chars().next() {
+        Some(c @ ('<' | '>')) => (Some(c), &value[1..]),
+        _ => (None, value),
+    };
+
+    if let Ok(date) = NaiveDate::parse_from_str(rest, "%Y-%m-%d") {
+        return Ok(match op {
+            Some('<') => TimeBound::Before(date),
+            Some('>') => TimeBound::After(date),
+            _ => TimeBound::On(date),
+        });
+    }
+
+    let age = parse_age(rest)
+        .ok_or_else(|| anyhow::anyhow!("Invalid time '{}' (use e.g. <14d, >2w or 2025-06-01)", value))?;
+    match op {
+        Some('<') => Ok(TimeBound::Within(age)),
+        Some('>') => Ok(TimeBound::OlderThan(age)),
+        _ => Err(anyhow::anyhow!("Relative time '{}' needs < or >, e.g. <{}", value, value)),
+    }
+}
+
+/// `12h`, `14d` or `2w`. Ages too large to represent are rejected.
+fn parse_age(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    match unit {
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+/// Compare types loosely so that `web-app`, `webapp` and `Web-App` agree.
+fn normalize_type(value: &str) -> String {
+    value.to_lowercase().replace(['-', '_', ' '], "")
+}
+
+/// Split on whitespace, keeping double-quoted sections together.
+fn tokenize(input: &str) -> anyhow::Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(anyhow::anyhow!("Unterminated quote in query '{}'", input));
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    fn names(query: &str, saved: &BTreeMap<String, String>, projects: &[Project]) -> Vec<String> {
+        let query = ProjectQuery::parse(query, saved).unwrap();
+        projects.iter().filter(|p| query.matches(p)).map(|p| p.name.clone()).collect()
+    }
+
+    fn sample() -> Vec<Project> {
+        let mut old = project("old cli").with_priority(Priority::Low).with_status(ProjectStatus::Testing).with_tags(&["rust"]);
+        old.updated = Utc::now() - Duration::days(30);
+        vec![
+            project("web app").with_priority(Priority::High).with_status(ProjectStatus::Development).with_tags(&["rust", "web"]),
+            project("docs").with_priority(Priority::High).with_status(ProjectStatus::Archived).with_tags(&["writing"]),
+            old,
+        ]
+        .into_iter()
+        .map(|p| p.with_type(ProjectType::WebApp))
+        .collect()
+    }
+
+    #[test]
+    fn test_query_terms() {
+        let projects = sample();
+        let saved = BTreeMap::new();
+
+        assert_eq!(names("status:development,testing", &saved, &projects), vec!["web app", "old cli"]);
+        assert_eq!(names("priority:high -status:archived", &saved, &projects), vec!["web app"]);
+        assert_eq!(names("tag:rust updated:<14d", &saved, &projects), vec!["web app"]);
+        assert_eq!(names("updated:>2w", &saved, &projects), vec!["old cli"]);
+        assert_eq!(names("type:web-app name:\"web app\"", &saved, &projects), vec!["web app"]);
+        assert_eq!(names("is:stale", &saved, &projects), vec!["old cli"]);
+        assert_eq!(names("WRITING", &saved, &projects), vec!["docs"]);
+        assert_eq!(names("", &saved, &projects).len(), 3);
+    }
+
+    #[test]
+    fn test_flag_filters() {
+        let projects = sample();
+        let query = ProjectQuery::parse("tag:rust", &BTreeMap::new()).unwrap()
+            .with_status(ProjectStatus::Testing)
+            .with_priority(Priority::Low);
+        let matched: Vec<&str> = projects.iter().filter(|p| query.matches(p)).map(|p| p.name.as_str()).collect();
+        assert_eq!(matched, vec!["old cli"]);
+
+        let mut projects = projects;
+        projects[1].project_type = crate::core::ProjectType::Tool;
+        let tools = ProjectQuery::default().with_type(&crate::core::ProjectType::Tool);
+        let matched: Vec<&str> = projects.iter().filter(|p| tools.matches(p)).map(|p| p.name.as_str()).collect();
+        assert_eq!(matched, vec!["docs"]);
+    }
+
+    #[test]
+    fn test_saved_queries() {
+        let projects = sample();
+        let mut saved = BTreeMap::new();
+        saved.insert("rusty".to_string(), "tag:rust".to_string());
+        saved.insert("focus".to_string(), "@rusty priority:high".to_string());
+        saved.insert("loop".to_string(), "@loop".to_string());
+
+        assert_eq!(names("@focus", &saved, &projects), vec!["web app"]);
+        assert!(ProjectQuery::parse("@loop", &saved).is_err());
+        assert!(ProjectQuery::parse("@missing", &saved).is_err());
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        let saved = BTreeMap::new();
+        for query in ["status:bogus", "colour:red", "updated:14d", "updated:<soon", "name:\"open", "tag:", "updated:<9999999999999999w", "created:>9999999999999999h"] {
+            assert!(ProjectQuery::parse(query, &saved).is_err(), "{} should not parse", query);
+        }
+    }
+}
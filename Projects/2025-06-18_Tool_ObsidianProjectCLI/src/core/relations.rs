@@ -0,0 +1,296 @@
+// Project dependency and relation management
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::core::{Project, ProjectStatus};
+use crate::utils::markdown::overview_wikilink;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationKind {
+    DependsOn,
+    Related,
+    Parent,
+}
+
+impl std::fmt::Display for RelationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelationKind::DependsOn => write!(f, "depends on"),
+            RelationKind::Related => write!(f, "related to"),
+            RelationKind::Parent => write!(f, "is a sub-project of"),
+        }
+    }
+}
+
+impl From<crate::cli::LinkKind> for RelationKind {
+    fn from(cli_kind: crate::cli::LinkKind) -> Self {
+        match cli_kind {
+            crate::cli::LinkKind::DependsOn => RelationKind::DependsOn,
+            crate::cli::LinkKind::Related => RelationKind::Related,
+            crate::cli::LinkKind::Parent => RelationKind::Parent,
+        }
+    }
+}
+
+pub fn find_by_id<'a>(projects: &'a [Project], id: &str) -> Option<&'a Project> {
+    projects.iter().find(|p| p.id == id)
+}
+
+/// Dependencies of `project` that are not completed yet.
+pub fn blocking_dependencies<'a>(project: &Project, projects: &'a [Project]) -> Vec<&'a Project> {
+    project.depends_on.iter()
+        .filter_map(|id| find_by_id(projects, id))
+        .filter(|dep| !dep.satisfies_dependency())
+        .collect()
+}
+
+/// Projects that depend on `project`.
+pub fn dependents<'a>(project: &Project, projects: &'a [Project]) -> Vec<&'a Project> {
+    projects.iter()
+        .filter(|p| p.depends_on.contains(&project.id))
+        .collect()
+}
+
+/// Direct sub-projects of `project`.
+pub fn children<'a>(project: &Project, projects: &'a [Project]) -> Vec<&'a Project> {
+    projects.iter()
+        .filter(|p| p.parent.as_deref() == Some(project.id.as_str()))
+        .collect()
+}
+
+/// If making `parent_id` the parent of `child_id` would introduce a cycle,
+/// return the chain of ancestors from `parent_id` up to `child_id`.
+pub fn find_parent_cycle(projects: &[Project], child_id: &str, parent_id: &str) -> Option<Vec<String>> {
+    let mut chain = vec![parent_id.to_string()];
+    let mut current = parent_id;
+
+    loop {
+        if current == child_id {
+            return Some(chain);
+        }
+        let parent = find_by_id(projects, current)?.parent.as_deref()?;
+        if chain.iter().any(|id| id == parent) {
+            // Pre-existing loop that does not involve `child_id`
+            return None;
+        }
+        chain.push(parent.to_string());
+        current = parent;
+    }
+}
+
+/// Progress of a project including its sub-projects: a parent's progress is
+/// the average of its children's rolled-up progress, ignoring cancelled
+/// children. Projects without (active) children report their own progress.
+pub fn rollup_progress(project: &Project, projects: &[Project]) -> f32 {
+    let mut visited = HashSet::new();
+    rollup_progress_inner(project, projects, &mut visited)
+}
+
+fn rollup_progress_inner(project: &Project, projects: &[Project], visited: &mut HashSet<String>) -> f32 {
+    if !visited.insert(project.id.clone()) {
+        return project.get_progress_percentage();
+    }
+
+    let progress: Vec<f32> = children(project, projects).into_iter()
+        .filter(|child| child.status != ProjectStatus::Cancelled)
+        .map(|child| rollup_progress_inner(child, projects, visited))
+        .collect();
+
+    if progress.is_empty() {
+        project.get_progress_percentage()
+    } else {
+        progress.iter().sum::<f32>() / progress.len() as f32
+    }
+}
+
+/// If adding "`from_id` depends on `to_id`" would introduce a cycle, return
+/// the chain of project IDs from `to_id` back to `from_id`.
+pub fn find_dependency_cycle(projects: &[Project], from_id: &str, to_id: &str) -> Option<Vec<String>> {
+    if from_id == to_id {
+        return Some(vec![from_id.to_string()]);
+    }
+
+    let mut visited = HashSet::new();
+    let mut path = vec![to_id.to_string()];
+    if dependency_path(projects, to_id, from_id, &mut visited, &mut path) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+fn dependency_path(
+    projects: &[Project],
+    current: &str,
+    target: &str,
+    visited: &mut HashSet<String>,
+    path: &mut Vec<String>,
+) -> bool {
+    if !visited.insert(current.to_string()) {
+        return false;
+    }
+
+    let Some(project) = find_by_id(projects, current) else {
+        return false;
+    };
+
+    for dep in &project.depends_on {
+        path.push(dep.clone());
+        if dep == target || dependency_path(projects, dep, target, visited, path) {
+            return true;
+        }
+        path.pop();
+    }
+
+    false
+}
+
+/// Body of the generated relations section of a project's overview note,
+/// or `None` when the project has no relations.
+pub fn render_relations_section(project: &Project, projects: &[Project], vault_path: &Path) -> Option<String> {
+    let links = |ids: &[String]| -> Vec<String> {
+        ids.iter()
+            .filter_map(|id| find_by_id(projects, id))
+            .map(|p| format!("- {}", overview_wikilink(p, vault_path)))
+            .collect()
+    };
+
+    let mut sections = Vec::new();
+
+    if let Some(parent) = project.parent.as_deref().and_then(|id| find_by_id(projects, id)) {
+        sections.push(format!("### Parent\n- {}", overview_wikilink(parent, vault_path)));
+    }
+
+    let sub_projects: Vec<String> = children(project, projects).iter()
+        .map(|p| format!("- {}", overview_wikilink(p, vault_path)))
+        .collect();
+    if !sub_projects.is_empty() {
+        sections.push(format!("### Sub-projects\n{}", sub_projects.join("\n")));
+    }
+
+    let depends_on = links(&project.depends_on);
+    if !depends_on.is_empty() {
+        sections.push(format!("### Depends On\n{}", depends_on.join("\n")));
+    }
+
+    let required_by: Vec<String> = dependents(project, projects).iter()
+        .map(|p| format!("- {}", overview_wikilink(p, vault_path)))
+        .collect();
+    if !required_by.is_empty() {
+        sections.push(format!("### Required By\n{}", required_by.join("\n")));
+    }
+
+    let related = links(&project.related);
+    if !related.is_empty() {
+        sections.push(format!("### Related\n{}", related.join("\n")));
+    }
+
+    if sections.is_empty() {
+        None
+    } else {
+        Some(format!("## Relations\n\n{}", sections.join("\n\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::project;
+
+    #[test]
+    fn test_find_dependency_cycle() {
+        let mut a = project("a");
+        let mut b = project("b");
+        let c = project("c");
+        a.add_relation(RelationKind::DependsOn, "b");
+        b.add_relation(RelationKind::DependsOn, "c");
+        let projects = vec![a, b, c];
+
+        // c -> a would close a -> b -> c -> a
+        assert_eq!(
+            find_dependency_cycle(&projects, "c", "a"),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(find_dependency_cycle(&projects, "a", "a"), Some(vec!["a".to_string()]));
+        assert_eq!(find_dependency_cycle(&projects, "a", "c"), None);
+    }
+
+    #[test]
+    fn test_blocking_dependencies() {
+        let mut web = project("web");
+        let mut api = project("api");
+        let mut lib = project("lib");
+        web.add_relation(RelationKind::DependsOn, "api");
+        web.add_relation(RelationKind::DependsOn, "lib");
+        assert!(!web.add_relation(RelationKind::DependsOn, "lib"));
+        lib.update_status(ProjectStatus::Completed, None);
+        api.update_status(ProjectStatus::Development, None);
+        let projects = vec![web.clone(), api, lib];
+
+        let blocking = blocking_dependencies(&web, &projects);
+        assert_eq!(blocking.len(), 1);
+        assert_eq!(blocking[0].id, "api");
+        assert_eq!(dependents(&projects[1], &projects).len(), 1);
+
+        assert!(web.remove_relation(RelationKind::DependsOn, "api"));
+        assert!(blocking_dependencies(&web, &projects).is_empty());
+    }
+
+    #[test]
+    fn test_render_relations_section() {
+        let vault = Path::new("/vault");
+        let mut web = project("web");
+        let mut api = project("api");
+        api.path = Some(vault.join("Projects/2025-06-18_API_api"));
+        web.path = Some(vault.join("Projects/2025-06-18_Web-App_web"));
+        web.add_relation(RelationKind::DependsOn, "api");
+        let projects = vec![web.clone(), api.clone()];
+
+        let web_section = render_relations_section(&web, &projects, vault).unwrap();
+        assert!(web_section.contains("### Depends On\n- [[Projects/2025-06-18_API_api/00_PROJECT-OVERVIEW|api]]"));
+
+        let api_section = render_relations_section(&api, &projects, vault).unwrap();
+        assert!(api_section.contains("### Required By\n- [[Projects/2025-06-18_Web-App_web/00_PROJECT-OVERVIEW|web]]"));
+
+        assert!(render_relations_section(&project("lonely"), &projects, vault).is_none());
+    }
+
+    #[test]
+    fn test_find_parent_cycle() {
+        let mut epic = project("epic");
+        let mut feature = project("feature");
+        let task = project("task");
+        feature.add_relation(RelationKind::Parent, "epic");
+        assert!(!feature.add_relation(RelationKind::Parent, "epic"));
+        epic.add_relation(RelationKind::Parent, "root");
+        let projects = vec![epic, feature, task];
+
+        // epic under feature would close feature -> epic -> feature
+        assert_eq!(
+            find_parent_cycle(&projects, "epic", "feature"),
+            Some(vec!["feature".to_string(), "epic".to_string()])
+        );
+        assert_eq!(find_parent_cycle(&projects, "task", "feature"), None);
+        assert_eq!(children(&projects[0], &projects).len(), 1);
+    }
+
+    #[test]
+    fn test_rollup_progress() {
+        let epic = project("epic");
+        let mut done = project("done");
+        let mut testing = project("testing");
+        let mut cancelled = project("cancelled");
+        for child in [&mut done, &mut testing, &mut cancelled] {
+            child.add_relation(RelationKind::Parent, "epic");
+        }
+        done.update_status(ProjectStatus::Completed, None);
+        testing.update_status(ProjectStatus::Testing, None);
+        cancelled.update_status(ProjectStatus::Cancelled, None);
+        let projects = vec![epic.clone(), done, testing.clone(), cancelled];
+
+        assert_eq!(rollup_progress(&epic, &projects), (100.0 + 85.0) / 2.0);
+        assert_eq!(rollup_progress(&testing, &projects), 85.0);
+        assert_eq!(rollup_progress(&project("lonely"), &projects), 10.0);
+    }
+}
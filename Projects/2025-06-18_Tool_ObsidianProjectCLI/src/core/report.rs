@@ -0,0 +1,317 @@
+// Progress reports over a date range (`opj report`)
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use std::path::Path;
+
+use crate::core::{is_stale, week_start, Project, ProjectStatus};
+use crate::utils::date::format_duration_hm;
+use crate::utils::error;
+use crate::utils::markdown::overview_wikilink;
+
+/// Inclusive range of calendar days (UTC) covered by a report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    pub fn new(start: NaiveDate, end: NaiveDate) -> anyhow::Result<Self> {
+        if start > end {
+            return Err(error::invalid_argument(format!("Report start {} is after its end {}", start, end)));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// The ISO week containing `today`, up to `today`.
+    pub fn week_to_date(today: NaiveDate) -> Self {
+        Self { start: week_start(today), end: today }
+    }
+
+    /// The calendar month containing `today`, up to `today`.
+    pub fn month_to_date(today: NaiveDate) -> Self {
+        Self { start: today.with_day(1).unwrap_or(today), end: today }
+    }
+
+    pub fn contains(&self, at: &DateTime<Utc>) -> bool {
+        let date = at.date_naive();
+        self.start <= date && date <= self.end
+    }
+}
+
+impl std::fmt::Display for DateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} – {}", self.start.format("%Y-%m-%d"), self.end.format("%Y-%m-%d"))
+    }
+}
+
+pub struct StatusTransition<'a> {
+    pub project: &'a Project,
+    pub from: ProjectStatus,
+    pub to: ProjectStatus,
+    pub at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+pub struct ProgressReport<'a> {
+    pub range: DateRange,
+    /// Status changes in the range, oldest first (creation entries excluded)
+    pub transitions: Vec<StatusTransition<'a>>,
+    pub created: Vec<&'a Project>,
+    pub completed: Vec<(&'a Project, DateTime<Utc>)>,
+    /// Active projects that are stale as of now
+    pub stale: Vec<&'a Project>,
+    /// Time logged per project in the range, most first
+    pub time_logged: Vec<(&'a Project, Duration)>,
+    pub total_time: Duration,
+}
+
+pub fn build_report<'a>(projects: &'a [Project], range: DateRange) -> ProgressReport<'a> {
+    let mut transitions: Vec<StatusTransition> = projects.iter()
+        .flat_map(|project| {
+            project.status_history.windows(2)
+                .filter(|pair| range.contains(&pair[1].timestamp))
+                .map(move |pair| StatusTransition {
+                    project,
+                    from: pair[0].status.clone(),
+                    to: pair[1].status.clone(),
+                    at: pair[1].timestamp,
+                    note: pair[1].note.clone(),
+                })
+        })
+        .collect();
+    transitions.sort_by_key(|t| t.at);
+
+    let mut created: Vec<&Project> = projects.iter()
+        .filter(|p| range.contains(&p.created))
+        .collect();
+    created.sort_by_key(|p| p.created);
+
+    let mut completed: Vec<(&Project, DateTime<Utc>)> = projects.iter()
+        .filter_map(|p| p.completed_at().map(|at| (p, at)))
+        .filter(|(_, at)| range.contains(at))
+        .collect();
+    completed.sort_by_key(|(_, at)| *at);
+
+    let mut stale: Vec<&Project> = projects.iter().filter(|p| is_stale(p)).collect();
+    stale.sort_by_key(|p| p.updated);
+
+    let mut time_logged: Vec<(&Project, Duration)> = projects.iter()
+        .map(|p| {
+            let total = p.time_entries.iter()
+                .filter(|entry| range.contains(&entry.start))
+                .fold(Duration::zero(), |total, entry| total + entry.duration());
+            (p, total)
+        })
+        .filter(|(_, total)| *total > Duration::zero())
+        .collect();
+    time_logged.sort_by_key(|(_, total)| std::cmp::Reverse(*total));
+    let total_time = time_logged.iter().fold(Duration::zero(), |sum, (_, total)| sum + *total);
+
+    ProgressReport { range, transitions, created, completed, stale, time_logged, total_time }
+}
+
+/// Render the report as an Obsidian note with wikilinks to each project.
+pub fn render_report_markdown(report: &ProgressReport, vault_path: &Path) -> String {
+    let link = |project: &Project| overview_wikilink(project, vault_path);
+
+    let mut out = format!("# Progress Report: {}\n\n", report.range);
+    out.push_str("## Summary\n\n| Metric | Value |\n| --- | --- |\n");
+    for (metric, value) in summary_rows(report) {
+        out.push_str(&format!("| {} | {} |\n", metric, value));
+    }
+
+    push_markdown_section(&mut out, "Status Changes", report.transitions.iter().map(|t| {
+        let mut line = format!(
+            "**{}** {}: {} → {}",
+            t.at.format("%Y-%m-%d"),
+            link(t.project),
+            t.from,
+            t.to
+        );
+        if let Some(note) = &t.note {
+            line.push_str(&format!(" — {}", note));
+        }
+        line
+    }));
+    push_markdown_section(&mut out, "New Projects", report.created.iter().map(|p| {
+        format!("{} · {} · created {}", link(p), p.project_type, p.created.format("%Y-%m-%d"))
+    }));
+    push_markdown_section(&mut out, "Completed Projects", report.completed.iter().map(|(p, at)| {
+        format!("{} · completed {}", link(p), at.format("%Y-%m-%d"))
+    }));
+    push_markdown_section(&mut out, "Stale Projects", report.stale.iter().map(|p| {
+        format!("{} · {} · last updated {}", link(p), p.status, p.updated.format("%Y-%m-%d"))
+    }));
+    push_markdown_section(&mut out, "Time Logged", report.time_logged.iter().map(|(p, total)| {
+        format!("{}: {}", link(p), format_duration_hm(total))
+    }));
+
+    out
+}
+
+/// Render the report as a standalone HTML page.
+pub fn render_report_html(report: &ProgressReport) -> String {
+    let title = format!("Progress Report: {}", report.range);
+
+    let mut out = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", escape_html(&title)));
+    out.push_str("<style>\nbody { font-family: sans-serif; max-width: 48rem; margin: 2rem auto; color: #222; }\n\
+        table { border-collapse: collapse; }\n\
+        th, td { border: 1px solid #ccc; padding: 0.25rem 0.75rem; text-align: left; }\n\
+        .empty { color: #888; font-style: italic; }\n</style>\n");
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", escape_html(&title)));
+
+    out.push_str("<h2>Summary</h2>\n<table>\n<tr><th>Metric</th><th>Value</th></tr>\n");
+    for (metric, value) in summary_rows(report) {
+        out.push_str(&format!("<tr><td>{}</td><td>{}</td></tr>\n", metric, escape_html(&value)));
+    }
+    out.push_str("</table>\n");
+
+    push_html_section(&mut out, "Status Changes", report.transitions.iter().map(|t| {
+        let mut line = format!(
+            "<strong>{}</strong> {}: {} → {}",
+            t.at.format("%Y-%m-%d"),
+            escape_html(&t.project.name),
+            t.from,
+            t.to
+        );
+        if let Some(note) = &t.note {
+            line.push_str(&format!(" — {}", escape_html(note)));
+        }
+        line
+    }));
+    push_html_section(&mut out, "New Projects", report.created.iter().map(|p| {
+        format!("{} · {} · created {}", escape_html(&p.name), escape_html(&p.project_type.to_string()), p.created.format("%Y-%m-%d"))
+    }));
+    push_html_section(&mut out, "Completed Projects", report.completed.iter().map(|(p, at)| {
+        format!("{} · completed {}", escape_html(&p.name), at.format("%Y-%m-%d"))
+    }));
+    push_html_section(&mut out, "Stale Projects", report.stale.iter().map(|p| {
+        format!("{} · {} · last updated {}", escape_html(&p.name), p.status, p.updated.format("%Y-%m-%d"))
+    }));
+    push_html_section(&mut out, "Time Logged", report.time_logged.iter().map(|(p, total)| {
+        format!("{}: {}", escape_html(&p.name), format_duration_hm(total))
+    }));
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn summary_rows(report: &ProgressReport) -> Vec<(&'static str, String)> {
+    vec![
+        ("Status changes", report.transitions.len().to_string()),
+        ("New projects", report.created.len().to_string()),
+        ("Completed projects", report.completed.len().to_string()),
+        ("Stale projects", report.stale.len().to_string()),
+        ("Time logged", format_duration_hm(&report.total_time)),
+    ]
+}
+
+fn push_markdown_section(out: &mut String, title: &str, lines: impl Iterator<Item = String>) {
+    out.push_str(&format!("\n## {}\n\n", title));
+    let mut empty = true;
+    for line in lines {
+        out.push_str(&format!("- {}\n", line));
+        empty = false;
+    }
+    if empty {
+        out.push_str("_None_\n");
+    }
+}
+
+fn push_html_section(out: &mut String, title: &str, lines: impl Iterator<Item = String>) {
+    out.push_str(&format!("<h2>{}</h2>\n", title));
+    let items: Vec<String> = lines.map(|line| format!("<li>{}</li>\n", line)).collect();
+    if items.is_empty() {
+        out.push_str("<p class=\"empty\">None</p>\n");
+    } else {
+        out.push_str("<ul>\n");
+        out.push_str(&items.concat());
+        out.push_str("</ul>\n");
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+    use crate::core::TimeEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        date(y, m, d).and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+
+    fn sample() -> Vec<Project> {
+        let mut old = project("old").created_at(at(2025, 5, 1)).with_dir("/vault/Projects/old");
+        old.update_status(ProjectStatus::Development, None);
+        old.status_history[1].timestamp = at(2025, 6, 2);
+        old.update_status(ProjectStatus::Completed, Some("Shipped <v1>".to_string()));
+        old.status_history[2].timestamp = at(2025, 6, 4);
+        old.time_entries.push(TimeEntry { start: at(2025, 6, 3), end: Some(at(2025, 6, 3) + Duration::minutes(90)), note: None });
+        old.time_entries.push(TimeEntry { start: at(2025, 5, 3), end: Some(at(2025, 5, 3) + Duration::hours(5)), note: None });
+
+        let new = project("new")
+            .created_at(at(2025, 6, 5))
+            .with_dir("/vault/Projects/new")
+            .with_type(crate::core::ProjectType::Custom("<b>".to_string()));
+        vec![old, new]
+    }
+
+    #[test]
+    fn test_date_ranges() {
+        let today = date(2025, 6, 18);
+        assert_eq!(DateRange::week_to_date(today).start, date(2025, 6, 16));
+        assert_eq!(DateRange::month_to_date(today).start, date(2025, 6, 1));
+        assert!(DateRange::new(today, date(2025, 6, 1)).is_err());
+
+        let range = DateRange::new(date(2025, 6, 1), date(2025, 6, 7)).unwrap();
+        assert!(range.contains(&at(2025, 6, 7)));
+        assert!(!range.contains(&at(2025, 6, 8)));
+    }
+
+    #[test]
+    fn test_build_report() {
+        let projects = sample();
+        let range = DateRange::new(date(2025, 6, 1), date(2025, 6, 7)).unwrap();
+        let report = build_report(&projects, range);
+
+        assert_eq!(report.transitions.len(), 2);
+        assert_eq!(report.transitions[0].from, ProjectStatus::Idea);
+        assert_eq!(report.transitions[1].to, ProjectStatus::Completed);
+        assert_eq!(report.created.len(), 1);
+        assert_eq!(report.created[0].name, "new");
+        assert_eq!(report.completed.len(), 1);
+        assert_eq!(report.total_time, Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_render_report() {
+        let projects = sample();
+        let range = DateRange::new(date(2025, 6, 1), date(2025, 6, 7)).unwrap();
+        let report = build_report(&projects, range);
+
+        let markdown = render_report_markdown(&report, Path::new("/vault"));
+        assert!(markdown.starts_with("# Progress Report: 2025-06-01 – 2025-06-07\n"));
+        assert!(markdown.contains("| Time logged | 1h 30m |"));
+        assert!(markdown.contains("- **2025-06-04** [[Projects/old/00_PROJECT-OVERVIEW|old]]: Development → Completed — Shipped <v1>\n"));
+        assert!(markdown.contains("## Stale Projects\n\n_None_\n"));
+
+        let html = render_report_html(&report);
+        assert!(html.contains("<li><strong>2025-06-04</strong> old: Development → Completed — Shipped &lt;v1&gt;</li>"));
+        assert!(html.contains("<li>new · &lt;b&gt; · created 2025-06-05</li>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+}
@@ -0,0 +1,118 @@
+// Aggregate statistics shared by `opj stats`, the dashboard and reports
+
+use chrono::Duration;
+
+use crate::core::{is_stale, Priority, Project, ProjectStatus, ALL_STATUSES};
+
+#[derive(Debug, Clone)]
+pub struct ProjectStats {
+    pub total: usize,
+    pub active: usize,
+    pub completed: usize,
+    pub stale: usize,
+    pub average_age_days: f32,
+    pub total_time: Duration,
+    /// Statuses with at least one project, in workflow order
+    pub by_status: Vec<(ProjectStatus, usize)>,
+    /// Project types by descending count
+    pub by_type: Vec<(String, usize)>,
+    /// Priorities with at least one project, highest first
+    pub by_priority: Vec<(Priority, usize)>,
+}
+
+impl ProjectStats {
+    pub fn compute(projects: &[Project]) -> Self {
+        let total = projects.len();
+
+        let average_age_days = if total > 0 {
+            projects.iter().map(|p| p.days_since_creation()).sum::<i64>() as f32 / total as f32
+        } else {
+            0.0
+        };
+
+        let by_status = ALL_STATUSES.iter()
+            .map(|status| (status.clone(), projects.iter().filter(|p| p.status == *status).count()))
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        let mut by_type: Vec<(String, usize)> = Vec::new();
+        for project in projects {
+            let type_name = project.project_type.to_string();
+            match by_type.iter_mut().find(|(name, _)| *name == type_name) {
+                Some((_, count)) => *count += 1,
+                None => by_type.push((type_name, 1)),
+            }
+        }
+        by_type.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        let by_priority = [Priority::High, Priority::Medium, Priority::Low].into_iter()
+            .map(|priority| {
+                let count = projects.iter().filter(|p| p.priority == priority).count();
+                (priority, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        Self {
+            total,
+            active: projects.iter().filter(|p| p.is_active()).count(),
+            completed: projects.iter().filter(|p| p.status == ProjectStatus::Completed).count(),
+            stale: projects.iter().filter(|p| is_stale(p)).count(),
+            average_age_days,
+            total_time: projects.iter()
+                .fold(Duration::zero(), |total, p| total + p.total_time_logged()),
+            by_status,
+            by_type,
+            by_priority,
+        }
+    }
+
+    /// Share of completed projects, in percent.
+    pub fn completion_rate(&self) -> f32 {
+        if self.total > 0 {
+            self.completed as f32 / self.total as f32 * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    #[test]
+    fn test_compute_stats() {
+        let mut tracked = project("a").with_priority(Priority::High).with_status(ProjectStatus::Development);
+        tracked.add_time_entry(Duration::minutes(90), None).unwrap();
+        let projects = vec![
+            tracked,
+            project("b").with_priority(Priority::Low).with_status(ProjectStatus::Completed),
+            project("c").with_type(crate::core::ProjectType::Api).with_priority(Priority::High),
+            project("d").with_priority(Priority::High).with_status(ProjectStatus::Completed),
+        ];
+
+        let stats = ProjectStats::compute(&projects);
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.active, 1);
+        assert_eq!(stats.completed, 2);
+        assert_eq!(stats.completion_rate(), 50.0);
+        assert_eq!(stats.total_time, Duration::minutes(90));
+        assert_eq!(stats.by_status, vec![
+            (ProjectStatus::Idea, 1),
+            (ProjectStatus::Development, 1),
+            (ProjectStatus::Completed, 2),
+        ]);
+        assert_eq!(stats.by_type, vec![("Tool".to_string(), 3), ("API".to_string(), 1)]);
+        assert_eq!(stats.by_priority, vec![(Priority::High, 3), (Priority::Low, 1)]);
+    }
+
+    #[test]
+    fn test_empty_stats() {
+        let stats = ProjectStats::compute(&[]);
+        assert_eq!(stats.total, 0);
+        assert_eq!(stats.completion_rate(), 0.0);
+        assert!(stats.by_status.is_empty());
+    }
+}
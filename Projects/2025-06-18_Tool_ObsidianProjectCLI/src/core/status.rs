@@ -11,6 +11,17 @@ pub fn create_status_entry(status: ProjectStatus, note: Option<String>) -> Statu
     }
 }
 
+/// Every status, in workflow order.
+pub const ALL_STATUSES: [ProjectStatus; 7] = [
+    ProjectStatus::Idea,
+    ProjectStatus::Planning,
+    ProjectStatus::Development,
+    ProjectStatus::Testing,
+    ProjectStatus::Completed,
+    ProjectStatus::Archived,
+    ProjectStatus::Cancelled,
+];
+
 pub fn get_status_progression() -> Vec<ProjectStatus> {
     vec![
         ProjectStatus::Idea,
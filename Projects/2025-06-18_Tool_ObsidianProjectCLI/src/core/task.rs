@@ -0,0 +1,267 @@
+// Checkbox task extraction and in-place editing for project notes
+
+use anyhow::Result;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use crate::fs::operations;
+use crate::utils::markdown::line_ending;
+
+#[derive(Debug, Clone)]
+pub struct Task {
+    pub id: usize,
+    pub file: PathBuf,
+    pub line: usize,
+    pub text: String,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskLine {
+    pub line: usize,
+    pub text: String,
+    pub done: bool,
+}
+
+fn task_regex() -> Regex {
+    Regex::new(r"^(\s*(?:[-*+]|\d+[.)])\s+\[)([ xX])(\]\s*)(.*)$").unwrap()
+}
+
+/// The fence character and length of a line opening or closing a fenced
+/// code block (three or more backticks or tildes).
+fn code_fence(line: &str) -> Option<(char, usize)> {
+    let line = line.trim_start();
+    let marker = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = line.chars().take_while(|c| *c == marker).count();
+    (length >= 3).then_some((marker, length))
+}
+
+/// Extract checkbox tasks from markdown content. Line numbers are 1-based
+/// and tasks inside fenced code blocks are ignored.
+pub fn extract_tasks(content: &str) -> Vec<TaskLine> {
+    let re = task_regex();
+    let mut tasks = Vec::new();
+    // A block is closed by a fence of the same character, at least as long
+    let mut open_fence: Option<(char, usize)> = None;
+
+    for (i, line) in content.lines().enumerate() {
+        if let Some((marker, length)) = code_fence(line) {
+            match open_fence {
+                None => open_fence = Some((marker, length)),
+                Some((open, open_length)) if open == marker && length >= open_length => open_fence = None,
+                Some(_) => {}
+            }
+            continue;
+        }
+        if open_fence.is_some() {
+            continue;
+        }
+
+        if let Some(caps) = re.captures(line) {
+            tasks.push(TaskLine {
+                line: i + 1,
+                text: caps[4].trim().to_string(),
+                done: &caps[2] != " ",
+            });
+        }
+    }
+
+    tasks
+}
+
+/// Collect the tasks of every markdown note in a project. IDs are assigned
+/// sequentially in file-name order so they stay stable between runs as
+/// long as the notes are not edited.
+pub async fn load_project_tasks(project_path: &Path) -> Result<Vec<Task>> {
+    let mut tasks = Vec::new();
+
+    for file in operations::find_files_with_extension(project_path, "md").await? {
+        let content = operations::read_file_to_string(&file).await?;
+        for task_line in extract_tasks(&content) {
+            tasks.push(Task {
+                id: tasks.len() + 1,
+                file: file.clone(),
+                line: task_line.line,
+                text: task_line.text,
+                done: task_line.done,
+            });
+        }
+    }
+
+    Ok(tasks)
+}
+
+/// Set the checkbox state of the task on `line` (1-based), leaving every
+/// other byte of the content untouched.
+pub fn set_task_done(content: &str, line: usize, done: bool) -> Result<String> {
+    let re = task_regex();
+    let mut result = String::with_capacity(content.len());
+    let mut found = false;
+
+    for (i, raw_line) in content.split_inclusive('\n').enumerate() {
+        if i + 1 != line {
+            result.push_str(raw_line);
+            continue;
+        }
+
+        let (body, ending) = split_line_ending(raw_line);
+        let caps = re.captures(body)
+            .ok_or_else(|| anyhow::anyhow!("Line {} is not a checkbox task", line))?;
+
+        result.push_str(&caps[1]);
+        result.push(if done { 'x' } else { ' ' });
+        result.push_str(&caps[3]);
+        result.push_str(&caps[4]);
+        result.push_str(ending);
+        found = true;
+    }
+
+    if !found {
+        return Err(anyhow::anyhow!("Line {} does not exist", line));
+    }
+
+    Ok(result)
+}
+
+/// Insert a new unchecked task. With a section heading the task is placed
+/// after the last non-empty line of that section (the section is appended
+/// if missing); otherwise it is appended to the end of the content.
+pub fn insert_task(content: &str, section: Option<&str>, text: &str) -> String {
+    let task_line = format!("- [ ] {}", text.trim());
+    let lines: Vec<&str> = content.lines().collect();
+
+    let section = match section {
+        Some(section) => section.trim(),
+        None => {
+            let insert_at = last_non_empty(&lines, 0, lines.len()).map(|i| i + 1).unwrap_or(0);
+            return rebuild(content, &lines, insert_at, &task_line);
+        }
+    };
+
+    let heading_index = lines.iter().position(|line| line.trim() == section);
+    match heading_index {
+        Some(start) => {
+            let level = heading_level(section);
+            let end = lines.iter().enumerate()
+                .skip(start + 1)
+                .find(|(_, line)| {
+                    let line_level = heading_level(line.trim());
+                    line_level > 0 && line_level <= level
+                })
+                .map(|(i, _)| i)
+                .unwrap_or(lines.len());
+
+            let insert_at = last_non_empty(&lines, start, end).map(|i| i + 1).unwrap_or(start + 1);
+            rebuild(content, &lines, insert_at, &task_line)
+        }
+        None => {
+            let eol = line_ending(content);
+            let mut result = content.trim_end_matches(['\r', '\n']).to_string();
+            if !result.is_empty() {
+                result.push_str(eol);
+                result.push_str(eol);
+            }
+            result.push_str(section);
+            result.push_str(eol);
+            result.push_str(eol);
+            result.push_str(&task_line);
+            result.push_str(eol);
+            result
+        }
+    }
+}
+
+fn rebuild(content: &str, lines: &[&str], index: usize, task_line: &str) -> String {
+    let mut lines = lines.to_vec();
+    lines.insert(index, task_line);
+    let eol = line_ending(content);
+    let mut result = lines.join(eol);
+    if content.ends_with('\n') || content.is_empty() {
+        result.push_str(eol);
+    }
+    result
+}
+
+fn last_non_empty(lines: &[&str], start: usize, end: usize) -> Option<usize> {
+    (start..end).rev().find(|&i| !lines[i].trim().is_empty())
+}
+
+fn heading_level(line: &str) -> usize {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level > 0 && line[level..].starts_with(' ') {
+        level
+    } else {
+        0
+    }
+}
+
+fn split_line_ending(line: &str) -> (&str, &str) {
+    if let Some(body) = line.strip_suffix("\r\n") {
+        (body, "\r\n")
+    } else if let Some(body) = line.strip_suffix('\n') {
+        (body, "\n")
+    } else {
+        (line, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str = "# Plan\n\n## Goals\n- [ ] First goal\n- [x] Second goal\n\n## Notes\n```\n- [ ] not a task\n```\n* [X] Other\n";
+
+    #[test]
+    fn test_extract_tasks() {
+        let tasks = extract_tasks(NOTE);
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0], TaskLine { line: 4, text: "First goal".to_string(), done: false });
+        assert!(tasks[1].done);
+        assert_eq!(tasks[2].line, 11);
+        assert!(tasks[2].done);
+
+        let tilde = "~~~\n- [ ] not a task\n```\n- [ ] still code\n~~~\n- [ ] Real\n";
+        let tasks = extract_tasks(tilde);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].line, 6);
+    }
+
+    #[test]
+    fn test_set_task_done_preserves_content() {
+        let updated = set_task_done(NOTE, 4, true).unwrap();
+        assert_eq!(updated, NOTE.replace("- [ ] First goal", "- [x] First goal"));
+
+        let reverted = set_task_done(&updated, 4, false).unwrap();
+        assert_eq!(reverted, NOTE);
+
+        assert!(set_task_done(NOTE, 1, true).is_err());
+        assert!(set_task_done(NOTE, 100, true).is_err());
+    }
+
+    #[test]
+    fn test_insert_task_into_section() {
+        let updated = insert_task(NOTE, Some("## Goals"), "Third goal");
+        assert!(updated.contains("- [x] Second goal\n- [ ] Third goal\n\n## Notes"));
+        assert!(updated.ends_with("* [X] Other\n"));
+    }
+
+    #[test]
+    fn test_insert_task_missing_section_and_append() {
+        let updated = insert_task("# Plan\n", Some("## Todo"), "Write docs");
+        assert_eq!(updated, "# Plan\n\n## Todo\n\n- [ ] Write docs\n");
+
+        let appended = insert_task("# Plan\n- [ ] a\n\n", None, "b");
+        assert_eq!(appended, "# Plan\n- [ ] a\n- [ ] b\n\n");
+    }
+
+    #[test]
+    fn test_insert_task_keeps_crlf() {
+        let note = NOTE.replace('\n', "\r\n");
+        let updated = insert_task(&note, Some("## Goals"), "Third goal");
+        assert_eq!(updated.replace("\r\n", "\n"), insert_task(NOTE, Some("## Goals"), "Third goal"));
+        assert_eq!(updated.matches('\n').count(), updated.matches("\r\n").count());
+
+        let updated = insert_task("# Plan\r\n", Some("## Todo"), "Write docs");
+        assert_eq!(updated, "# Plan\r\n\r\n## Todo\r\n\r\n- [ ] Write docs\r\n");
+    }
+}
@@ -16,3 +16,14 @@ impl Template {
         }
     }
 }
+
+/// Template directory used for new projects of a type, when there is one.
+pub fn template_name(project_type: &crate::core::ProjectType) -> Option<&'static str> {
+    match project_type {
+        crate::core::ProjectType::WebApp => Some("Web-App-Project"),
+        crate::core::ProjectType::Tool => Some("Tool-Project"),
+        crate::core::ProjectType::Content => Some("Content-Project"),
+        crate::core::ProjectType::Api => Some("API-Project"),
+        crate::core::ProjectType::Custom(_) => None,
+    }
+}
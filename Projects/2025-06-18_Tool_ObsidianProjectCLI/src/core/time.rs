@@ -0,0 +1,85 @@
+// Time tracking for work sessions
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimeEntry {
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+}
+
+impl TimeEntry {
+    pub fn is_running(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// Length of the session; running sessions are measured up to now.
+    pub fn duration(&self) -> Duration {
+        self.end.unwrap_or_else(Utc::now).signed_duration_since(self.start)
+    }
+}
+
+/// Start (Monday) of the ISO week containing `date`.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Total time per week for the last `weeks` weeks (oldest first), counting
+/// each session in the week it started.
+pub fn weekly_totals<'a, I>(entries: I, weeks: usize, now: DateTime<Utc>) -> Vec<(NaiveDate, Duration)>
+where
+    I: IntoIterator<Item = &'a TimeEntry>,
+{
+    let current_week = week_start(now.date_naive());
+    let mut totals: Vec<(NaiveDate, Duration)> = (0..weeks)
+        .rev()
+        .map(|i| (current_week - Duration::weeks(i as i64), Duration::zero()))
+        .collect();
+
+    for entry in entries {
+        let entry_week = week_start(entry.start.date_naive());
+        if let Some((_, total)) = totals.iter_mut().find(|(week, _)| *week == entry_week) {
+            *total += entry.duration();
+        }
+    }
+
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(start: DateTime<Utc>, minutes: i64) -> TimeEntry {
+        TimeEntry {
+            start,
+            end: Some(start + Duration::minutes(minutes)),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_week_start() {
+        let wednesday = NaiveDate::from_ymd_opt(2025, 6, 18).unwrap();
+        assert_eq!(week_start(wednesday), NaiveDate::from_ymd_opt(2025, 6, 16).unwrap());
+    }
+
+    #[test]
+    fn test_weekly_totals() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 18, 12, 0, 0).unwrap();
+        let entries = vec![
+            entry(Utc.with_ymd_and_hms(2025, 6, 17, 9, 0, 0).unwrap(), 90),
+            entry(Utc.with_ymd_and_hms(2025, 6, 16, 9, 0, 0).unwrap(), 30),
+            entry(Utc.with_ymd_and_hms(2025, 6, 10, 9, 0, 0).unwrap(), 60),
+            entry(Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap(), 60),
+        ];
+
+        let totals = weekly_totals(&entries, 2, now);
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[0], (NaiveDate::from_ymd_opt(2025, 6, 9).unwrap(), Duration::minutes(60)));
+        assert_eq!(totals[1], (NaiveDate::from_ymd_opt(2025, 6, 16).unwrap(), Duration::minutes(120)));
+    }
+}
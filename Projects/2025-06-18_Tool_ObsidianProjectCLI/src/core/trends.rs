@@ -0,0 +1,303 @@
+// Time-series analytics derived from each project's status history
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+
+use crate::core::{week_start, Project, ProjectStatus, ALL_STATUSES};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interval {
+    Week,
+    Month,
+}
+
+impl Interval {
+    /// Start of the week or month containing `date`.
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Interval::Week => week_start(date),
+            Interval::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// Start of the following week or month.
+    pub fn next(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Interval::Week => start + Duration::weeks(1),
+            Interval::Month => start.checked_add_months(chrono::Months::new(1)).unwrap_or(start),
+        }
+    }
+
+    fn previous(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Interval::Week => start - Duration::weeks(1),
+            Interval::Month => start.checked_sub_months(chrono::Months::new(1)).unwrap_or(start),
+        }
+    }
+
+    /// Short label such as `2025-W23` or `2025-06`.
+    pub fn label(&self, start: NaiveDate) -> String {
+        match self {
+            Interval::Week => start.format("%G-W%V").to_string(),
+            Interval::Month => start.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+impl From<crate::cli::TrendInterval> for Interval {
+    fn from(cli_interval: crate::cli::TrendInterval) -> Self {
+        match cli_interval {
+            crate::cli::TrendInterval::Week => Interval::Week,
+            crate::cli::TrendInterval::Month => Interval::Month,
+        }
+    }
+}
+
+/// Activity within one week or month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendBucket {
+    pub start: NaiveDate,
+    /// First day of the next bucket
+    pub end: NaiveDate,
+    /// Transitions into Completed
+    pub completed: usize,
+    /// Transitions into Development
+    pub started: usize,
+    /// Active (Planning, Development or Testing) projects at the end of the
+    /// bucket, or now for the current one
+    pub wip: usize,
+}
+
+/// Development → Completed span of one finished project.
+pub struct CycleTime<'a> {
+    pub project: &'a Project,
+    pub started: DateTime<Utc>,
+    pub completed: DateTime<Utc>,
+}
+
+impl CycleTime<'_> {
+    pub fn duration(&self) -> Duration {
+        self.completed.signed_duration_since(self.started)
+    }
+}
+
+/// Time all projects spent in one status.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTime {
+    pub status: ProjectStatus,
+    pub total: Duration,
+    /// Projects that were in the status at some point
+    pub projects: usize,
+}
+
+impl StatusTime {
+    pub fn average(&self) -> Duration {
+        if self.projects == 0 {
+            Duration::zero()
+        } else {
+            self.total / self.projects as i32
+        }
+    }
+}
+
+pub struct Trends<'a> {
+    pub interval: Interval,
+    /// Oldest first, ending with the current week or month
+    pub buckets: Vec<TrendBucket>,
+    /// Most recently completed first
+    pub cycle_times: Vec<CycleTime<'a>>,
+    /// Statuses that any project has been in, in workflow order
+    pub time_in_status: Vec<StatusTime>,
+}
+
+impl Trends<'_> {
+    pub fn average_cycle_time(&self) -> Option<Duration> {
+        if self.cycle_times.is_empty() {
+            return None;
+        }
+        let total = self.cycle_times.iter().fold(Duration::zero(), |total, c| total + c.duration());
+        Some(total / self.cycle_times.len() as i32)
+    }
+
+    pub fn median_cycle_time(&self) -> Option<Duration> {
+        let mut durations: Vec<Duration> = self.cycle_times.iter().map(CycleTime::duration).collect();
+        durations.sort();
+        let mid = durations.len() / 2;
+        match durations.len() {
+            0 => None,
+            len if len % 2 == 0 => Some((durations[mid - 1] + durations[mid]) / 2),
+            _ => Some(durations[mid]),
+        }
+    }
+}
+
+pub fn compute_trends(projects: &[Project], interval: Interval, count: usize, now: DateTime<Utc>) -> Trends<'_> {
+    let mut starts = vec![interval.start_of(now.date_naive())];
+    while starts.len() < count.max(1) {
+        let previous = interval.previous(*starts.last().unwrap());
+        starts.push(previous);
+    }
+    starts.reverse();
+
+    let buckets = starts.into_iter()
+        .map(|start| {
+            let end = interval.next(start);
+            let in_bucket = |at: &DateTime<Utc>| at.date_naive() >= start && at.date_naive() < end;
+            let entered = |status: ProjectStatus| projects.iter()
+                .flat_map(transitions)
+                .filter(|(to, at)| *to == status && in_bucket(at))
+                .count();
+
+            let instant = end.and_hms_opt(0, 0, 0).unwrap().and_utc().min(now);
+            TrendBucket {
+                start,
+                end,
+                completed: entered(ProjectStatus::Completed),
+                started: entered(ProjectStatus::Development),
+                wip: projects.iter()
+                    .filter(|p| matches!(
+                        status_at(p, instant),
+                        Some(ProjectStatus::Planning | ProjectStatus::Development | ProjectStatus::Testing)
+                    ))
+                    .count(),
+            }
+        })
+        .collect();
+
+    let mut cycle_times: Vec<CycleTime> = projects.iter().filter_map(cycle_time).collect();
+    cycle_times.sort_by_key(|c| std::cmp::Reverse(c.completed));
+
+    let spans: Vec<(ProjectStatus, Duration, &str)> = projects.iter()
+        .flat_map(|p| status_spans(p, now).into_iter().map(move |(status, duration)| (status, duration, p.id.as_str())))
+        .collect();
+    let time_in_status = ALL_STATUSES.iter()
+        .filter_map(|status| {
+            let mut visitors: Vec<&str> = spans.iter().filter(|(s, _, _)| s == status).map(|(_, _, id)| *id).collect();
+            visitors.dedup();
+            if visitors.is_empty() {
+                return None;
+            }
+            Some(StatusTime {
+                status: status.clone(),
+                total: spans.iter()
+                    .filter(|(s, _, _)| s == status)
+                    .fold(Duration::zero(), |total, (_, duration, _)| total + *duration),
+                projects: visitors.len(),
+            })
+        })
+        .collect();
+
+    Trends { interval, buckets, cycle_times, time_in_status }
+}
+
+/// Status changes of a project as `(new status, when)`, excluding the
+/// initial entry recorded at creation.
+fn transitions(project: &Project) -> impl Iterator<Item = (ProjectStatus, DateTime<Utc>)> + '_ {
+    project.status_history.iter()
+        .skip(1)
+        .map(|entry| (entry.status.clone(), entry.timestamp))
+}
+
+/// Status of a project at `instant`, or `None` if it did not exist yet.
+fn status_at(project: &Project, instant: DateTime<Utc>) -> Option<ProjectStatus> {
+    project.status_history.iter()
+        .filter(|entry| entry.timestamp <= instant)
+        .max_by_key(|entry| entry.timestamp)
+        .map(|entry| entry.status.clone())
+}
+
+/// How long each history entry lasted; the current status runs until `now`.
+fn status_spans(project: &Project, now: DateTime<Utc>) -> Vec<(ProjectStatus, Duration)> {
+    let mut history: Vec<_> = project.status_history.iter().collect();
+    history.sort_by_key(|entry| entry.timestamp);
+
+    history.iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let until = history.get(i + 1).map(|next| next.timestamp).unwrap_or(now);
+            (entry.status.clone(), until.signed_duration_since(entry.timestamp).max(Duration::zero()))
+        })
+        .collect()
+}
+
+/// From the first move into Development to the last completion after it.
+fn cycle_time(project: &Project) -> Option<CycleTime<'_>> {
+    let completed = project.completed_at()?;
+    let started = project.status_history.iter()
+        .filter(|entry| entry.status == ProjectStatus::Development && entry.timestamp <= completed)
+        .map(|entry| entry.timestamp)
+        .min()?;
+
+    Some(CycleTime { project, started, completed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::StatusEntry;
+    use chrono::TimeZone;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, day, 12, 0, 0).unwrap()
+    }
+
+    fn project(name: &str, history: &[(ProjectStatus, u32)]) -> Project {
+        let mut project = crate::core::fixture::project(name);
+        project.status_history = history.iter()
+            .map(|(status, day)| StatusEntry { status: status.clone(), timestamp: at(*day), note: None })
+            .collect();
+        project.status = history.last().unwrap().0.clone();
+        project
+    }
+
+    #[test]
+    fn test_interval_buckets() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        assert_eq!(Interval::Month.start_of(date), NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(Interval::Month.next(NaiveDate::from_ymd_opt(2025, 12, 1).unwrap()), NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+        assert_eq!(Interval::Week.start_of(date), date);
+        assert_eq!(Interval::Week.label(date), "2025-W14");
+        assert_eq!(Interval::Month.label(date), "2025-03");
+    }
+
+    #[test]
+    fn test_compute_trends() {
+        use ProjectStatus::*;
+
+        // Week of Monday June 2nd and of Monday June 9th
+        let projects = vec![
+            project("a", &[(Idea, 2), (Development, 3), (Completed, 10)]),
+            project("b", &[(Idea, 2), (Development, 4), (Testing, 11)]),
+            project("c", &[(Idea, 9)]),
+        ];
+
+        let trends = compute_trends(&projects, Interval::Week, 2, at(13));
+        let weeks: Vec<(usize, usize, usize)> = trends.buckets.iter().map(|b| (b.completed, b.started, b.wip)).collect();
+        assert_eq!(weeks, vec![(0, 2, 2), (1, 0, 1)]);
+        assert_eq!(trends.buckets[0].start, NaiveDate::from_ymd_opt(2025, 6, 2).unwrap());
+
+        assert_eq!(trends.cycle_times.len(), 1);
+        assert_eq!(trends.average_cycle_time(), Some(Duration::days(7)));
+
+        let development = trends.time_in_status.iter().find(|s| s.status == Development).unwrap();
+        assert_eq!(development.total, Duration::days(14));
+        assert_eq!(development.projects, 2);
+        assert_eq!(development.average(), Duration::days(7));
+        assert!(trends.time_in_status.iter().all(|s| s.status != Archived));
+    }
+
+    #[test]
+    fn test_median_cycle_time() {
+        use ProjectStatus::*;
+
+        let projects = vec![
+            project("a", &[(Development, 1), (Completed, 3)]),
+            project("b", &[(Development, 1), (Completed, 5)]),
+            project("c", &[(Development, 1), (Completed, 11)]),
+            project("d", &[(Development, 1), (Completed, 21)]),
+        ];
+
+        let trends = compute_trends(&projects, Interval::Month, 1, at(25));
+        assert_eq!(trends.median_cycle_time(), Some(Duration::days(7)));
+        assert_eq!(trends.buckets[0].completed, 4);
+    }
+}
@@ -0,0 +1,367 @@
+// Backup archives (`.tar.zst`) of project folders, with a manifest
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::core::Project;
+use crate::utils::markdown::vault_relative;
+
+/// Default folder for backups, relative to the vault root
+pub const BACKUP_DIR: &str = ".opj/backups";
+pub const BACKUP_EXTENSION: &str = "tar.zst";
+const MANIFEST_FILE: &str = "manifest.json";
+/// Folder inside the archive that mirrors the vault
+const FILES_DIR: &str = "files";
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format: u32,
+    pub opj_version: String,
+    pub created: DateTime<Utc>,
+    pub projects: Vec<BackupEntry>,
+}
+
+/// One project folder in a backup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    pub id: String,
+    pub name: String,
+    pub status: String,
+    /// Folder relative to the vault, with `/`
+    pub path: String,
+    pub files: usize,
+}
+
+/// What to do when a project in a backup already exists in the vault.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnConflict {
+    /// Restore nothing if any project conflicts
+    Fail,
+    /// Leave existing projects alone
+    Skip,
+    /// Write the backed-up files over the existing ones
+    Overwrite,
+}
+
+impl From<crate::cli::ConflictPolicy> for OnConflict {
+    fn from(policy: crate::cli::ConflictPolicy) -> Self {
+        match policy {
+            crate::cli::ConflictPolicy::Fail => OnConflict::Fail,
+            crate::cli::ConflictPolicy::Skip => OnConflict::Skip,
+            crate::cli::ConflictPolicy::Overwrite => OnConflict::Overwrite,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestoreAction {
+    Restore,
+    Overwrite,
+    Skip,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestoreItem {
+    pub entry: BackupEntry,
+    pub action: RestoreAction,
+    /// Why the project conflicts with the vault, if it does
+    pub conflict: Option<String>,
+}
+
+impl BackupManifest {
+    /// Entry for a project ID or name, matched like `opj` commands match projects.
+    pub fn find(&self, name: &str) -> Option<&BackupEntry> {
+        let lower_name = name.to_lowercase();
+        self.projects.iter().find(|p| p.id == name)
+            .or_else(|| self.projects.iter().find(|p| p.name.to_lowercase() == lower_name))
+            .or_else(|| self.projects.iter().find(|p| p.name.to_lowercase().contains(&lower_name)))
+    }
+}
+
+fn is_write_in_progress(path: &Path) -> bool {
+    let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    name == crate::fs::lock::LOCK_FILE || (name.starts_with('.') && name.ends_with(".tmp"))
+}
+
+/// Write `projects` (which must live inside `vault_path`) to a new archive.
+pub fn create_backup(out: &Path, vault_path: &Path, projects: &[Project]) -> Result<BackupManifest> {
+    let mut entries = Vec::new();
+    let mut folders = Vec::new();
+    for project in projects {
+        let Some(dir) = &project.path else { continue };
+        let paths: Vec<PathBuf> = WalkDir::new(dir)
+            .sort_by_file_name()
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .into_iter()
+            .map(|entry| entry.into_path())
+            // A lock or half-written file restored later would only get in the way
+            .filter(|path| !is_write_in_progress(path))
+            .collect();
+
+        entries.push(BackupEntry {
+            id: project.id.clone(),
+            name: project.name.clone(),
+            status: project.status.to_string(),
+            path: vault_relative(dir, vault_path),
+            files: paths.iter().filter(|path| path.is_file()).count(),
+        });
+        folders.push(paths);
+    }
+
+    let manifest = BackupManifest {
+        format: FORMAT_VERSION,
+        opj_version: env!("CARGO_PKG_VERSION").to_string(),
+        created: Utc::now(),
+        projects: entries,
+    };
+
+    if let Some(parent) = out.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let file = File::create(out).with_context(|| format!("Failed to create {}", out.display()))?;
+    let encoder = zstd::Encoder::new(file, 0)?;
+    let mut archive = tar::Builder::new(encoder);
+
+    let manifest_json = serde_json::to_vec_pretty(&manifest)?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(manifest.created.timestamp().max(0) as u64);
+    header.set_cksum();
+    archive.append_data(&mut header, MANIFEST_FILE, manifest_json.as_slice())?;
+
+    for path in folders.iter().flatten() {
+        let name = Path::new(FILES_DIR).join(vault_relative(path, vault_path));
+        archive.append_path_with_name(path, &name)
+            .with_context(|| format!("Failed to add {} to the backup", path.display()))?;
+    }
+
+    archive.into_inner()?.finish()?;
+    Ok(manifest)
+}
+
+/// The manifest of a backup, read without unpacking anything else.
+pub fn read_manifest(archive_path: &Path) -> Result<BackupManifest> {
+    let mut archive = open_archive(archive_path)?;
+    let mut entries = archive.entries()?;
+    let mut entry = entries.next()
+        .with_context(|| format!("{} is empty", archive_path.display()))??;
+    if entry.path()?.as_ref() != Path::new(MANIFEST_FILE) {
+        return Err(anyhow::anyhow!("{} is not an opj backup (no manifest)", archive_path.display()));
+    }
+
+    let manifest: BackupManifest = serde_json::from_reader(&mut entry)
+        .with_context(|| format!("Invalid manifest in {}", archive_path.display()))?;
+    if manifest.format > FORMAT_VERSION {
+        return Err(anyhow::anyhow!(
+            "{} was made by a newer opj ({}); upgrade to restore it",
+            archive_path.display(),
+            manifest.opj_version
+        ));
+    }
+    Ok(manifest)
+}
+
+/// Decide per backed-up project whether to restore it. `current` are the
+/// projects in the vault now; a project conflicts when its folder exists or
+/// its ID is in use by another folder.
+pub fn plan_restore(
+    manifest: &BackupManifest,
+    vault_path: &Path,
+    current: &[Project],
+    on_conflict: OnConflict,
+) -> Vec<RestoreItem> {
+    manifest.projects.iter()
+        .map(|entry| {
+            let target = vault_path.join(&entry.path);
+            let moved = current.iter()
+                .find(|p| p.id == entry.id && p.path.as_ref().is_some_and(|path| *path != target));
+            let conflict = match moved {
+                Some(project) => Some(format!(
+                    "ID is used by {}",
+                    project.path.as_deref().map(|path| vault_relative(path, vault_path)).unwrap_or_default()
+                )),
+                None if target.exists() => Some(format!("{} already exists", entry.path)),
+                None => None,
+            };
+
+            let action = match (&conflict, on_conflict) {
+                (None, _) => RestoreAction::Restore,
+                // Writing over another folder would leave two projects with one ID
+                (Some(_), OnConflict::Overwrite) if moved.is_none() => RestoreAction::Overwrite,
+                _ => RestoreAction::Skip,
+            };
+            RestoreItem { entry: entry.clone(), action, conflict }
+        })
+        .collect()
+}
+
+/// Unpack the files of the given project folders into the vault. Returns the
+/// number of files written.
+pub fn extract_projects(archive_path: &Path, vault_path: &Path, folders: &[&str]) -> Result<usize> {
+    let mut archive = open_archive(archive_path)?;
+    let mut written = 0;
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        let Ok(relative) = path.strip_prefix(FILES_DIR) else { continue };
+        if relative.components().any(|component| !matches!(component, Component::Normal(_))) {
+            return Err(anyhow::anyhow!("Refusing to restore unsafe path {}", path.display()));
+        }
+        let relative_str = relative.to_string_lossy().replace('\\', "/");
+        if !folders.iter().any(|folder| relative_str == *folder || relative_str.starts_with(&format!("{}/", folder))) {
+            continue;
+        }
+
+        // opj never writes links, and one could point the entries after it out of the vault
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_symlink() || entry_type.is_hard_link() {
+            return Err(anyhow::anyhow!("Refusing to restore link {}", path.display()));
+        }
+
+        let target = vault_path.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+            // A link already in the vault must not redirect the file either
+            let inside = match (parent.canonicalize(), vault_path.canonicalize()) {
+                (Ok(parent), Ok(vault)) => parent.starts_with(vault),
+                _ => false,
+            };
+            if !inside {
+                return Err(anyhow::anyhow!("Refusing to restore {} outside the vault", path.display()));
+            }
+        }
+        if entry.header().entry_type().is_file() {
+            crate::fs::history::record_write(&target)?;
+        }
+        entry.unpack(&target)
+            .with_context(|| format!("Failed to restore {}", target.display()))?;
+        if entry.header().entry_type().is_file() {
+            written += 1;
+        }
+    }
+
+    Ok(written)
+}
+
+/// Backups in `dir`, newest first, with the manifest of each readable one.
+pub fn list_backups(dir: &Path) -> Vec<(PathBuf, Result<BackupManifest>)> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut backups: Vec<(PathBuf, Result<BackupManifest>)> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(&format!(".{}", BACKUP_EXTENSION)))
+        .map(|path| {
+            let manifest = read_manifest(&path);
+            (path, manifest)
+        })
+        .collect();
+
+    backups.sort_by(|(a_path, a), (b_path, b)| {
+        let created = |manifest: &Result<BackupManifest>| manifest.as_ref().ok().map(|m| m.created);
+        created(b).cmp(&created(a)).then(b_path.cmp(a_path))
+    });
+    backups
+}
+
+fn open_archive(archive_path: &Path) -> Result<tar::Archive<zstd::Decoder<'static, std::io::BufReader<File>>>> {
+    let file = File::open(archive_path)
+        .with_context(|| format!("Failed to open {}", archive_path.display()))?;
+    let decoder = zstd::Decoder::new(file)
+        .with_context(|| format!("{} is not a zstd archive", archive_path.display()))?;
+    Ok(tar::Archive::new(decoder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::ProjectFixture;
+    use tempfile::tempdir;
+
+    fn project(vault: &Path, folder: &str) -> Project {
+        let dir = vault.join("Projects").join(folder);
+        std::fs::create_dir_all(dir.join("notes")).unwrap();
+        std::fs::write(dir.join(".project-meta.yaml"), format!("name: {}\n", folder)).unwrap();
+        std::fs::write(dir.join("notes").join("log.md"), "# Log\n").unwrap();
+
+        crate::core::fixture::project(folder).with_dir(dir)
+    }
+
+    #[test]
+    fn test_backup_and_restore() {
+        let vault = tempdir().unwrap();
+        let projects = vec![project(vault.path(), "App"), project(vault.path(), "Site")];
+        let out = vault.path().join(BACKUP_DIR).join("test.tar.zst");
+
+        let manifest = create_backup(&out, vault.path(), &projects).unwrap();
+        assert_eq!(manifest.projects[0].path, "Projects/App");
+        assert_eq!(manifest.projects[0].files, 2);
+        assert_eq!(read_manifest(&out).unwrap(), manifest);
+        assert_eq!(list_backups(&vault.path().join(BACKUP_DIR)).len(), 1);
+
+        // App is gone, Site was edited since
+        std::fs::remove_dir_all(vault.path().join("Projects/App")).unwrap();
+        std::fs::write(vault.path().join("Projects/Site/notes/log.md"), "# Edited\n").unwrap();
+        let current = vec![projects[1].clone()];
+
+        let actions = |policy| plan_restore(&manifest, vault.path(), &current, policy)
+            .into_iter()
+            .map(|item| item.action)
+            .collect::<Vec<_>>();
+        assert_eq!(actions(OnConflict::Skip), vec![RestoreAction::Restore, RestoreAction::Skip]);
+        assert_eq!(actions(OnConflict::Overwrite), vec![RestoreAction::Restore, RestoreAction::Overwrite]);
+
+        assert_eq!(extract_projects(&out, vault.path(), &["Projects/App"]).unwrap(), 2);
+        assert!(vault.path().join("Projects/App/notes/log.md").exists());
+        assert_eq!(std::fs::read_to_string(vault.path().join("Projects/Site/notes/log.md")).unwrap(), "# Edited\n");
+    }
+
+    #[test]
+    fn test_restore_rejects_links() {
+        let vault = tempdir().unwrap();
+        let outside = tempdir().unwrap();
+        let out = vault.path().join("evil.tar.zst");
+
+        let encoder = zstd::Encoder::new(File::create(&out).unwrap(), 0).unwrap().auto_finish();
+        let mut builder = tar::Builder::new(encoder);
+        let mut link = tar::Header::new_gnu();
+        link.set_entry_type(tar::EntryType::Symlink);
+        link.set_size(0);
+        builder.append_link(&mut link, "files/Projects/App/link", outside.path()).unwrap();
+        let mut file = tar::Header::new_gnu();
+        file.set_size(4);
+        file.set_mode(0o644);
+        builder.append_data(&mut file, "files/Projects/App/link/x", &b"evil"[..]).unwrap();
+        builder.into_inner().unwrap();
+
+        assert!(extract_projects(&out, vault.path(), &["Projects/App"]).is_err());
+        assert!(!outside.path().join("x").exists());
+        assert!(!vault.path().join("Projects/App/link").exists());
+    }
+
+    #[test]
+    fn test_restore_conflicts_on_moved_project() {
+        let vault = tempdir().unwrap();
+        let original = project(vault.path(), "App");
+        let out = vault.path().join("app.tar.zst");
+        let manifest = create_backup(&out, vault.path(), std::slice::from_ref(&original)).unwrap();
+
+        let mut moved = original.clone();
+        moved.path = Some(vault.path().join("Projects/_ARCHIVE/2026/App"));
+        std::fs::remove_dir_all(vault.path().join("Projects/App")).unwrap();
+
+        let plan = plan_restore(&manifest, vault.path(), &[moved], OnConflict::Overwrite);
+        assert_eq!(plan[0].action, RestoreAction::Skip);
+        assert_eq!(plan[0].conflict.as_deref(), Some("ID is used by Projects/_ARCHIVE/2026/App"));
+    }
+}
@@ -0,0 +1,154 @@
+// On-disk index of parsed projects, so unchanged projects are not reparsed
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::core::Project;
+use crate::fs::operations::FileState;
+use crate::utils::markdown::OVERVIEW_FILE;
+
+/// Cache directory, relative to the vault root
+pub const CACHE_DIR: &str = ".opj/cache";
+const INDEX_FILE: &str = "index.json";
+
+/// File states that decide whether a cached project is still valid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stamp {
+    pub metadata: Option<FileState>,
+    pub overview: Option<FileState>,
+    /// Changes when entries are added to or removed from the folder
+    pub dir: Option<SystemTime>,
+    /// Newest note, since editing a note in place changes neither of the above
+    pub notes: Option<DateTime<Utc>>,
+}
+
+impl Stamp {
+    pub fn of(project_dir: &Path) -> Self {
+        Self {
+            metadata: FileState::of(&project_dir.join(".project-meta.yaml")),
+            overview: FileState::of(&project_dir.join(OVERVIEW_FILE)),
+            dir: std::fs::metadata(project_dir).and_then(|m| m.modified()).ok(),
+            notes: crate::fs::scanner::latest_note_edit(project_dir),
+        }
+    }
+}
+
+/// A parsed project, with the fields its metadata does not store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub stamp: Stamp,
+    pub project: Project,
+    pub metadata_state: Option<FileState>,
+    pub last_edited: Option<DateTime<Utc>>,
+}
+
+impl IndexEntry {
+    pub fn new(stamp: Stamp, project: Project) -> Self {
+        Self {
+            stamp,
+            metadata_state: project.metadata_state.clone(),
+            last_edited: project.last_edited,
+            project,
+        }
+    }
+}
+
+/// Parsed projects by directory. Entries from another opj version are
+/// discarded, since the project format may have changed.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectIndex {
+    version: String,
+    pub entries: BTreeMap<PathBuf, IndexEntry>,
+}
+
+impl ProjectIndex {
+    pub fn new() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// The index in `cache_dir`, or an empty one if it is missing, unreadable
+    /// or from another version.
+    pub fn load(cache_dir: &Path) -> Self {
+        std::fs::read_to_string(cache_dir.join(INDEX_FILE)).ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|index| index.version == env!("CARGO_PKG_VERSION"))
+            .unwrap_or_else(Self::new)
+    }
+
+    /// Cached project for `dir`, as it was parsed, if its files have not
+    /// changed since.
+    pub fn get(&self, dir: &Path, stamp: &Stamp) -> Option<Project> {
+        let entry = self.entries.get(dir).filter(|entry| entry.stamp == *stamp)?;
+        let mut project = entry.project.clone();
+        project.path = Some(dir.to_path_buf());
+        project.last_edited = entry.last_edited;
+        project.metadata_state = entry.metadata_state.clone();
+        Some(project)
+    }
+
+    /// Write the index through a temporary file, so a reader never sees a
+    /// half-written index.
+    pub fn save(&self, cache_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(cache_dir)
+            .with_context(|| format!("Failed to create {}", cache_dir.display()))?;
+        let temp_path = cache_dir.join(format!("{}.tmp", INDEX_FILE));
+        std::fs::write(&temp_path, serde_json::to_string(self)?)
+            .context("Failed to write project index")?;
+        std::fs::rename(&temp_path, cache_dir.join(INDEX_FILE))
+            .context("Failed to replace project index")?;
+        Ok(())
+    }
+
+    /// Delete the index. Returns whether there was one.
+    pub fn clear(cache_dir: &Path) -> Result<bool> {
+        let path = cache_dir.join(INDEX_FILE);
+        if !path.exists() {
+            return Ok(false);
+        }
+        std::fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_index_roundtrip_and_invalidation() {
+        let vault = tempdir().unwrap();
+        let cache_dir = vault.path().join(CACHE_DIR);
+        let project_dir = vault.path().join("Projects").join("A");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join(OVERVIEW_FILE), "# A\n").unwrap();
+
+        let mut project = crate::core::fixture::project("A");
+        project.last_edited = Some(Utc::now());
+        let mut index = ProjectIndex::new();
+        index.entries.insert(project_dir.clone(), IndexEntry::new(Stamp::of(&project_dir), project.clone()));
+        index.save(&cache_dir).unwrap();
+
+        let loaded = ProjectIndex::load(&cache_dir);
+        let cached = loaded.get(&project_dir, &Stamp::of(&project_dir)).unwrap();
+        assert_eq!(cached.name, "A");
+        assert_eq!(cached.last_edited, project.last_edited);
+        assert_eq!(cached.path.as_deref(), Some(project_dir.as_path()));
+
+        // Same modification time, different size
+        let mut later = Stamp::of(&project_dir);
+        later.overview.as_mut().unwrap().len += 1;
+        assert!(loaded.get(&project_dir, &later).is_none());
+
+        assert!(ProjectIndex::clear(&cache_dir).unwrap());
+        assert!(ProjectIndex::load(&cache_dir).entries.is_empty());
+        assert!(!ProjectIndex::clear(&cache_dir).unwrap());
+    }
+}
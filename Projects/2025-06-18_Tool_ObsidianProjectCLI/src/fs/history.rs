@@ -0,0 +1,392 @@
+// Journal of the files opj changes, behind `opj history` and `opj undo`.
+//
+// Before a command writes a file in the vault, the file's current content is
+// copied into `<vault>/.opj/history/<operation>/files/`. One operation covers
+// everything a command changed, so it can be reverted as a unit.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::fs::operations::{backup_file, FileState};
+
+/// Journal directory, relative to the vault root
+pub const HISTORY_DIR: &str = ".opj/history";
+const OPERATION_FILE: &str = "operation.json";
+const FILES_DIR: &str = "files";
+/// Operations kept; older ones are deleted
+const MAX_OPERATIONS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Change {
+    /// A file was written. `backup` names the saved earlier content, or is
+    /// empty when the file did not exist; `after` is its state once the
+    /// command finished.
+    Write {
+        path: PathBuf,
+        backup: Option<String>,
+        after: Option<FileState>,
+    },
+    /// A folder was moved
+    Move { from: PathBuf, to: PathBuf },
+    /// A folder was created; `after` lists its files once the command
+    /// finished. Writes inside it are not journaled separately.
+    CreateDir {
+        path: PathBuf,
+        after: Option<Vec<(PathBuf, FileState)>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Operation {
+    pub id: String,
+    /// Command that made the changes, e.g. `status`
+    pub command: String,
+    pub started: DateTime<Utc>,
+    pub changes: Vec<Change>,
+    #[serde(default)]
+    pub undone: bool,
+}
+
+impl Operation {
+    /// Why undoing would lose later edits, one message per file or folder.
+    /// `newer` are the operations undone before this one; what they touched
+    /// is put back by then and is not a conflict.
+    pub fn undo_conflicts(&self, newer: &[Operation]) -> Vec<String> {
+        self.changes.iter()
+            .filter_map(|change| match change {
+                Change::Write { path, after, .. } => (FileState::of(path) != *after && !touched(newer, path))
+                    .then(|| format!("{} changed since", path.display())),
+                Change::CreateDir { path, after } => {
+                    (dir_files(path) != after.clone().unwrap_or_default() && !touched(newer, path))
+                        .then(|| format!("{} changed since", path.display()))
+                }
+                Change::Move { from, to } => {
+                    if !to.exists() && !touched(newer, to) {
+                        Some(format!("{} no longer exists", to.display()))
+                    } else if from.exists() && !touched(newer, from) {
+                        Some(format!("{} exists again", from.display()))
+                    } else {
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Whether this operation wrote `path` or moved it or a folder holding it.
+    pub fn touches(&self, path: &Path) -> bool {
+        self.changes.iter().any(|change| match change {
+            Change::Write { path: written, .. } => written == path,
+            Change::Move { from, to } => path.starts_with(from) || path.starts_with(to),
+            Change::CreateDir { path: created, .. } => path.starts_with(created),
+        })
+    }
+}
+
+fn touched(operations: &[Operation], path: &Path) -> bool {
+    operations.iter().any(|operation| operation.touches(path))
+}
+
+/// Records the changes of the running command.
+pub struct Journal {
+    vault_path: PathBuf,
+    current: Option<Operation>,
+}
+
+impl Journal {
+    pub fn new(vault_path: &Path) -> Self {
+        Self { vault_path: vault_path.to_path_buf(), current: None }
+    }
+
+    pub fn history_path(&self) -> PathBuf {
+        self.vault_path.join(HISTORY_DIR)
+    }
+
+    /// Save the current content of `path` before it is overwritten. Files
+    /// outside the vault or in `.opj` are not journaled.
+    pub fn record_write(&mut self, path: &Path) -> Result<()> {
+        if !path.starts_with(&self.vault_path) || path.starts_with(self.vault_path.join(".opj")) {
+            return Ok(());
+        }
+        let recorded = self.current.as_ref().is_some_and(|operation| {
+            operation.changes.iter().any(|change| match change {
+                Change::Write { path: written, .. } => written == path,
+                Change::CreateDir { path: created, .. } => path.starts_with(created),
+                Change::Move { .. } => false,
+            })
+        });
+        if recorded {
+            return Ok(());
+        }
+
+        let operation_dir = self.begin()?;
+        let index = self.current.as_ref().map_or(0, |operation| operation.changes.len());
+        let backup = match path.is_file() {
+            // One folder per change, so files with the same name do not collide
+            true => {
+                let backup_dir = operation_dir.join(FILES_DIR).join(index.to_string());
+                std::fs::create_dir_all(&backup_dir)
+                    .with_context(|| format!("Failed to create {}", backup_dir.display()))?;
+                let saved = backup_file(path, Some(&backup_dir))
+                    .with_context(|| format!("Failed to save {} to the history", path.display()))?;
+                saved.strip_prefix(operation_dir.join(FILES_DIR)).ok()
+                    .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+            }
+            false => None,
+        };
+
+        self.push(Change::Write { path: path.to_path_buf(), backup, after: None })
+    }
+
+    /// Note a folder the command created, so undo can remove it again.
+    pub fn record_create_dir(&mut self, path: &Path) -> Result<()> {
+        if !path.starts_with(&self.vault_path) {
+            return Ok(());
+        }
+        self.begin()?;
+        self.push(Change::CreateDir { path: path.to_path_buf(), after: None })
+    }
+
+    pub fn record_move(&mut self, from: &Path, to: &Path) -> Result<()> {
+        self.begin()?;
+        self.push(Change::Move { from: from.to_path_buf(), to: to.to_path_buf() })
+    }
+
+    /// Close the current operation, noting the final state of its files, and
+    /// drop the oldest operations.
+    pub fn finish(&mut self) -> Result<()> {
+        let Some(mut operation) = self.current.take() else { return Ok(()) };
+        for change in &mut operation.changes {
+            match change {
+                Change::Write { path, after, .. } => *after = FileState::of(path),
+                Change::CreateDir { path, after } => *after = Some(dir_files(path)),
+                Change::Move { .. } => {}
+            }
+        }
+        save_operation(&self.history_path(), &operation)?;
+
+        let history_path = self.history_path();
+        for old in operations(&history_path).iter().skip(MAX_OPERATIONS) {
+            let _ = std::fs::remove_dir_all(history_path.join(&old.id));
+        }
+        Ok(())
+    }
+
+    fn begin(&mut self) -> Result<PathBuf> {
+        let operation = self.current.get_or_insert_with(|| Operation {
+            id: format!("{}-{}", chrono::Local::now().format("%Y%m%d-%H%M%S-%3f"), std::process::id()),
+            command: crate::utils::output::command_name().to_string(),
+            started: Utc::now(),
+            changes: Vec::new(),
+            undone: false,
+        });
+        Ok(self.vault_path.join(HISTORY_DIR).join(&operation.id))
+    }
+
+    /// Add a change and persist it right away, so a crash still leaves it undoable.
+    fn push(&mut self, change: Change) -> Result<()> {
+        let history_path = self.history_path();
+        let operation = self.current.as_mut().expect("operation started");
+        operation.changes.push(change);
+        save_operation(&history_path, operation)
+    }
+}
+
+/// Operations in the history, newest first.
+pub fn operations(history_path: &Path) -> Vec<Operation> {
+    let Ok(entries) = std::fs::read_dir(history_path) else { return Vec::new() };
+    let mut operations: Vec<Operation> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| std::fs::read_to_string(entry.path().join(OPERATION_FILE)).ok())
+        .filter_map(|content| serde_json::from_str(&content).ok())
+        .collect();
+    operations.sort_by(|a, b| b.started.cmp(&a.started).then(b.id.cmp(&a.id)));
+    operations
+}
+
+/// Put back what the operation changed, newest change first, and mark it undone.
+pub fn undo(history_path: &Path, operation: &Operation) -> Result<()> {
+    let operation_dir = history_path.join(&operation.id);
+
+    for change in operation.changes.iter().rev() {
+        match change {
+            Change::Write { path, backup: Some(backup), .. } => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create {}", parent.display()))?;
+                }
+                std::fs::copy(operation_dir.join(FILES_DIR).join(backup), path)
+                    .with_context(|| format!("Failed to restore {}", path.display()))?;
+            }
+            Change::Write { path, backup: None, .. } => {
+                if path.is_file() {
+                    std::fs::remove_file(path)
+                        .with_context(|| format!("Failed to remove {}", path.display()))?;
+                }
+            }
+            Change::CreateDir { path, .. } => {
+                if path.is_dir() {
+                    std::fs::remove_dir_all(path)
+                        .with_context(|| format!("Failed to remove {}", path.display()))?;
+                }
+            }
+            Change::Move { from, to } => {
+                if let Some(parent) = from.parent() {
+                    std::fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create {}", parent.display()))?;
+                }
+                std::fs::rename(to, from)
+                    .with_context(|| format!("Failed to move {} back to {}", to.display(), from.display()))?;
+            }
+        }
+    }
+
+    let mut operation = operation.clone();
+    operation.undone = true;
+    save_operation(history_path, &operation)
+}
+
+/// Files under `dir` with their state, in a stable order.
+fn dir_files(dir: &Path) -> Vec<(PathBuf, FileState)> {
+    walkdir::WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| Some((entry.path().to_path_buf(), FileState::of(entry.path())?)))
+        .collect()
+}
+
+fn save_operation(history_path: &Path, operation: &Operation) -> Result<()> {
+    let operation_dir = history_path.join(&operation.id);
+    std::fs::create_dir_all(&operation_dir)
+        .with_context(|| format!("Failed to create {}", operation_dir.display()))?;
+    std::fs::write(operation_dir.join(OPERATION_FILE), serde_json::to_string_pretty(operation)?)
+        .context("Failed to write the history")
+}
+
+static JOURNAL: Mutex<Option<Journal>> = Mutex::new(None);
+
+/// Start journaling writes into this vault. Called once settings are known.
+pub fn set_vault(vault_path: &Path) {
+    let mut journal = JOURNAL.lock().unwrap_or_else(|e| e.into_inner());
+    if journal.is_none() {
+        *journal = Some(Journal::new(vault_path));
+    }
+}
+
+/// Journal the current content of `path` before writing it.
+pub fn record_write(path: &Path) -> Result<()> {
+    with_journal(|journal| journal.record_write(path))
+}
+
+/// Journal a folder the command just created.
+pub fn record_create_dir(path: &Path) -> Result<()> {
+    with_journal(|journal| journal.record_create_dir(path))
+}
+
+/// Journal a folder move, after it happened.
+pub fn record_move(from: &Path, to: &Path) -> Result<()> {
+    with_journal(|journal| journal.record_move(from, to))
+}
+
+/// End the current operation. Long-running commands call this after each
+/// change so they can be undone one at a time.
+pub fn finish() -> Result<()> {
+    with_journal(Journal::finish)
+}
+
+fn with_journal(f: impl FnOnce(&mut Journal) -> Result<()>) -> Result<()> {
+    let mut journal = JOURNAL.lock().unwrap_or_else(|e| e.into_inner());
+    match journal.as_mut() {
+        Some(journal) => f(journal),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_journal_and_undo() {
+        let vault = tempdir().unwrap();
+        let note = vault.path().join("Projects/App/notes.md");
+        let created = vault.path().join("Projects/App/new.md");
+        std::fs::create_dir_all(note.parent().unwrap()).unwrap();
+        std::fs::write(&note, "before").unwrap();
+
+        let mut journal = Journal::new(vault.path());
+        journal.record_write(&note).unwrap();
+        std::fs::write(&note, "after").unwrap();
+        journal.record_write(&note).unwrap();
+        std::fs::write(&note, "after again").unwrap();
+        journal.record_write(&created).unwrap();
+        std::fs::write(&created, "new").unwrap();
+        journal.record_write(&vault.path().join(".opj/cache/index.json")).unwrap();
+        journal.finish().unwrap();
+
+        let history = operations(&journal.history_path());
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].changes.len(), 2);
+        assert!(history[0].undo_conflicts(&[]).is_empty());
+
+        undo(&journal.history_path(), &history[0]).unwrap();
+        assert_eq!(std::fs::read_to_string(&note).unwrap(), "before");
+        assert!(!created.exists());
+        assert!(operations(&journal.history_path())[0].undone);
+    }
+
+    #[test]
+    fn test_undo_created_folder() {
+        let vault = tempdir().unwrap();
+        let dir = vault.path().join("Projects/New");
+
+        let mut journal = Journal::new(vault.path());
+        std::fs::create_dir_all(&dir).unwrap();
+        journal.record_create_dir(&dir).unwrap();
+        journal.record_write(&dir.join("notes.md")).unwrap();
+        std::fs::write(dir.join("notes.md"), "template").unwrap();
+        journal.finish().unwrap();
+
+        let operation = operations(&journal.history_path()).remove(0);
+        assert_eq!(operation.changes.len(), 1, "writes inside a created folder are covered by it");
+        assert!(operation.undo_conflicts(&[]).is_empty());
+
+        std::fs::write(dir.join("mine.md"), "added by hand").unwrap();
+        assert_eq!(operation.undo_conflicts(&[]).len(), 1);
+        std::fs::remove_file(dir.join("mine.md")).unwrap();
+
+        undo(&journal.history_path(), &operation).unwrap();
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_undo_conflicts_and_moves() {
+        let vault = tempdir().unwrap();
+        let from = vault.path().join("Projects/App");
+        let to = vault.path().join("Projects/_ARCHIVE/2026/App");
+        std::fs::create_dir_all(&from).unwrap();
+        std::fs::create_dir_all(to.parent().unwrap()).unwrap();
+
+        let mut journal = Journal::new(vault.path());
+        std::fs::rename(&from, &to).unwrap();
+        journal.record_move(&from, &to).unwrap();
+        journal.record_write(&to.join("notes.md")).unwrap();
+        std::fs::write(to.join("notes.md"), "written").unwrap();
+        journal.finish().unwrap();
+
+        let operation = operations(&journal.history_path()).remove(0);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        std::fs::write(to.join("notes.md"), "edited by hand, longer").unwrap();
+        assert_eq!(operation.undo_conflicts(&[]).len(), 1);
+
+        // Forced: the note did not exist before, so it goes and the folder moves back
+        undo(&journal.history_path(), &operation).unwrap();
+        assert!(from.is_dir() && !to.exists());
+    }
+}
@@ -0,0 +1,125 @@
+// Per-project lock, so two opj processes never write the same metadata at once
+
+use anyhow::{Context, Result};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::utils::error;
+
+/// Lock file, next to the metadata it guards
+pub const LOCK_FILE: &str = ".project-meta.yaml.lock";
+/// How long to wait for another process to release the lock
+const WAIT: Duration = Duration::from_secs(5);
+const RETRY: Duration = Duration::from_millis(50);
+/// Locks older than this were left behind by a process that died
+const STALE_AFTER: Duration = Duration::from_secs(30);
+
+/// Held while a project's metadata is written; released when dropped.
+#[derive(Debug)]
+pub struct ProjectLock {
+    path: PathBuf,
+}
+
+impl ProjectLock {
+    pub async fn acquire(project_dir: &Path) -> Result<Self> {
+        let path = project_dir.join(LOCK_FILE);
+        let started = SystemTime::now();
+
+        loop {
+            match std::fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    let _ = writeln!(file, "{}", std::process::id());
+                    return Ok(Self { path });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&path) {
+                        take_over_stale(&path);
+                        continue;
+                    }
+                    if started.elapsed().unwrap_or_default() >= WAIT {
+                        return Err(error::conflict(format!(
+                            "{} is locked by another opj process (delete {} if none is running)",
+                            project_dir.display(),
+                            path.display()
+                        )));
+                    }
+                    tokio::time::sleep(RETRY).await;
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to create {}", path.display()));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ProjectLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Move a stale lock out of the way. Renaming is atomic, so of several
+/// processes that found it stale only one gets it; if the lock was replaced
+/// by a live one in the meantime, that one is put back.
+fn take_over_stale(path: &Path) {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".stale.{}", std::process::id()));
+    let moved = PathBuf::from(name);
+
+    if std::fs::rename(path, &moved).is_err() {
+        return;
+    }
+    if !is_stale(&moved) {
+        // Fails only if yet another process already holds the lock
+        let _ = std::fs::hard_link(&moved, path);
+    }
+    let _ = std::fs::remove_file(&moved);
+}
+
+fn is_stale(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age >= STALE_AFTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_lock_is_exclusive_and_released() {
+        let dir = tempdir().unwrap();
+        let lock = ProjectLock::acquire(dir.path()).await.unwrap();
+        assert!(dir.path().join(LOCK_FILE).exists());
+
+        let second = tokio::time::timeout(Duration::from_millis(200), ProjectLock::acquire(dir.path())).await;
+        assert!(second.is_err(), "a held lock must not be acquired twice");
+
+        drop(lock);
+        assert!(!dir.path().join(LOCK_FILE).exists());
+        ProjectLock::acquire(dir.path()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stale_lock_is_taken_over() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE);
+        let file = std::fs::File::create(&path).unwrap();
+        file.set_modified(SystemTime::now() - STALE_AFTER * 2).unwrap();
+        drop(file);
+
+        let lock = ProjectLock::acquire(dir.path()).await.unwrap();
+        assert!(!is_stale(&path));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        // A live lock is never taken over
+        take_over_stale(&path);
+        assert!(path.exists());
+        drop(lock);
+    }
+}
@@ -1,5 +1,10 @@
+pub mod backup;
+pub mod cache;
+pub mod history;
+pub mod lock;
 pub mod operations;
 pub mod scanner;
+pub mod watcher;
 
 pub use operations::*;
 pub use scanner::*;
@@ -1,7 +1,11 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use anyhow::{Result, Context};
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 use walkdir::WalkDir;
 
+use crate::utils::markdown::{replace_managed_region, rewrite_wikilinks, vault_relative};
+
 pub async fn copy_dir_recursive<P: AsRef<Path>>(src: P, dst: P) -> Result<()> {
     let src = src.as_ref();
     let dst = dst.as_ref();
@@ -61,6 +65,20 @@ pub async fn ensure_directory_exists<P: AsRef<Path>>(path: P) -> Result<()> {
     Ok(())
 }
 
+/// Size and modification time, to tell whether a file changed since.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileState {
+    pub len: u64,
+    pub modified: Option<SystemTime>,
+}
+
+impl FileState {
+    pub fn of(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok().filter(|m| m.is_file())?;
+        Some(Self { len: metadata.len(), modified: metadata.modified().ok() })
+    }
+}
+
 pub async fn read_file_to_string<P: AsRef<Path>>(path: P) -> Result<String> {
     let path = path.as_ref();
     
@@ -76,10 +94,59 @@ pub async fn write_string_to_file<P: AsRef<Path>>(path: P, content: &str) -> Res
         ensure_directory_exists(parent).await?;
     }
 
+    crate::fs::history::record_write(path)?;
     tokio::fs::write(path, content).await
         .with_context(|| format!("Failed to write file: {}", path.display()))
 }
 
+/// Write through a temporary file in the same folder and rename it into
+/// place, so an interrupted write leaves the old content intact.
+pub async fn write_atomic<P: AsRef<Path>>(path: P, content: &str) -> Result<()> {
+    let path = path.as_ref();
+    let file_name = path.file_name()
+        .with_context(|| format!("Invalid file path: {}", path.display()))?;
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name.to_string_lossy(), std::process::id()));
+
+    crate::fs::history::record_write(path)?;
+    let written = async {
+        let mut file = tokio::fs::File::create(&temp_path).await?;
+        tokio::io::AsyncWriteExt::write_all(&mut file, content.as_bytes()).await?;
+        file.sync_all().await?;
+        tokio::fs::rename(&temp_path, path).await
+    }.await;
+    if written.is_err() {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+    }
+    written.with_context(|| format!("Failed to write file: {}", path.display()))
+}
+
+/// Rewrite the named generated regions of a note, creating the note with the
+/// given heading when it does not exist. Returns whether the file changed.
+pub async fn update_managed_regions<P: AsRef<Path>>(
+    path: P,
+    heading: &str,
+    regions: &[(&str, String)],
+) -> Result<bool> {
+    let path = path.as_ref();
+
+    let content = if path.exists() {
+        read_file_to_string(path).await?
+    } else {
+        format!("# {}\n", heading)
+    };
+
+    let updated = regions.iter().fold(content.clone(), |note, (name, body)| {
+        replace_managed_region(&note, name, Some(body))
+    });
+
+    if updated == content && path.exists() {
+        return Ok(false);
+    }
+
+    write_string_to_file(path, &updated).await?;
+    Ok(true)
+}
+
 pub async fn file_exists<P: AsRef<Path>>(path: P) -> bool {
     tokio::fs::metadata(path.as_ref()).await.is_ok()
 }
@@ -132,7 +199,40 @@ pub async fn find_files_with_extension<P: AsRef<Path>>(
     Ok(files)
 }
 
-pub async fn backup_file<P: AsRef<Path>>(path: P) -> Result<std::path::PathBuf> {
+/// Rewrite wikilinks into `old_dir` in every note of the vault to point into
+/// `new_dir`, skipping hidden folders such as `.obsidian`. Returns the notes
+/// that link there; with `dry_run` they are only reported.
+pub async fn update_vault_links(vault_path: &Path, old_dir: &Path, new_dir: &Path, dry_run: bool) -> Result<Vec<PathBuf>> {
+    let old = vault_relative(old_dir, vault_path);
+    let new = vault_relative(new_dir, vault_path);
+    let mut changed = Vec::new();
+
+    let notes = WalkDir::new(vault_path)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|ext| ext == "md"));
+
+    for note in notes {
+        let Ok(content) = tokio::fs::read_to_string(note.path()).await else { continue };
+        let updated = rewrite_wikilinks(&content, &old, &new);
+        if updated == content {
+            continue;
+        }
+        if !dry_run {
+            write_string_to_file(note.path(), &updated).await
+                .with_context(|| format!("Failed to update links in {}", note.path().display()))?;
+        }
+        changed.push(note.path().to_path_buf());
+    }
+
+    changed.sort();
+    Ok(changed)
+}
+
+/// Copy a file to `<stem>.backup.<timestamp>.<ext>` in `backup_dir`, or next
+/// to the file when no directory is given. Returns the copy's path.
+pub fn backup_file<P: AsRef<Path>>(path: P, backup_dir: Option<&Path>) -> Result<std::path::PathBuf> {
     let path = path.as_ref();
     
     if !path.exists() {
@@ -140,7 +240,7 @@ pub async fn backup_file<P: AsRef<Path>>(path: P) -> Result<std::path::PathBuf>
     }
 
     let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-    let backup_path = if let Some(parent) = path.parent() {
+    let backup_path = if let Some(parent) = backup_dir.or(path.parent()) {
         let file_stem = path.file_stem()
             .and_then(|s| s.to_str())
             .unwrap_or("backup");
@@ -154,7 +254,7 @@ pub async fn backup_file<P: AsRef<Path>>(path: P) -> Result<std::path::PathBuf>
         std::path::PathBuf::from(format!("{}.backup.{}", path.display(), timestamp))
     };
 
-    tokio::fs::copy(path, &backup_path).await
+    std::fs::copy(path, &backup_path)
         .with_context(|| format!("Failed to create backup: {} -> {}", path.display(), backup_path.display()))?;
 
     Ok(backup_path)
@@ -187,6 +287,27 @@ mod tests {
         assert_eq!(content1, "content1");
     }
 
+    #[tokio::test]
+    async fn test_update_managed_regions() {
+        let temp_dir = tempdir().unwrap();
+        let note = temp_dir.path().join("Dashboard.md");
+
+        let regions = [("summary", "Two projects".to_string())];
+        assert!(update_managed_regions(&note, "Dashboard", &regions).await.unwrap());
+        assert!(!update_managed_regions(&note, "Dashboard", &regions).await.unwrap());
+
+        let content = tokio::fs::read_to_string(&note).await.unwrap();
+        assert!(content.starts_with("# Dashboard\n"));
+        tokio::fs::write(&note, format!("{}\nMy notes\n", content)).await.unwrap();
+
+        let regions = [("summary", "Three projects".to_string())];
+        assert!(update_managed_regions(&note, "Dashboard", &regions).await.unwrap());
+        let content = tokio::fs::read_to_string(&note).await.unwrap();
+        assert!(content.contains("Three projects"));
+        assert!(!content.contains("Two projects"));
+        assert!(content.contains("My notes"));
+    }
+
     #[tokio::test]
     async fn test_ensure_directory_exists() {
         let temp_dir = tempdir().unwrap();
@@ -212,10 +333,15 @@ mod tests {
         assert_eq!(read_content, content);
 
         // Backup file
-        let backup_path = backup_file(&file_path).await.unwrap();
+        let backup_path = backup_file(&file_path, None).unwrap();
         assert!(backup_path.exists());
         
         let backup_content = read_file_to_string(&backup_path).await.unwrap();
         assert_eq!(backup_content, content);
+
+        let backup_dir = temp_dir.path().join("backups");
+        std::fs::create_dir_all(&backup_dir).unwrap();
+        let backup_path = backup_file(&file_path, Some(&backup_dir)).unwrap();
+        assert_eq!(backup_path.parent(), Some(backup_dir.as_path()));
     }
 }
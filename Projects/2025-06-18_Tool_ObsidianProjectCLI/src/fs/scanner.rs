@@ -1,76 +1,205 @@
 use std::path::{Path, PathBuf};
 use anyhow::{Result, Context};
 use crate::core::Project;
-
+use crate::fs::cache::{IndexEntry, ProjectIndex, Stamp};
+use crate::fs::operations::FileState;
+use crate::utils::markdown::OVERVIEW_FILE;
+use walkdir::WalkDir;
+
+/// Finds project directories below one or more roots. A directory is a
+/// project when it has a metadata file or an overview note, or is named
+/// `YYYY-MM-DD_Type_Name`; other directories are searched further down.
+#[derive(Clone)]
 pub struct ProjectScanner {
-    projects_path: PathBuf,
+    roots: Vec<PathBuf>,
+    max_depth: usize,
+    ignore: Vec<glob::Pattern>,
+    /// Index of previously parsed projects, if caching is enabled
+    cache_dir: Option<PathBuf>,
+    /// Archive folder, skipped unless archived projects are included
+    archive: Option<PathBuf>,
+}
+
+/// A project directory that could not be loaded and was left out of a scan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanWarning {
+    pub project_dir: PathBuf,
+    /// The metadata file that failed to parse, or the directory itself
+    pub path: PathBuf,
+    pub message: String,
+    /// One-based position of a YAML error, when known
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl ScanWarning {
+    fn new(project_dir: &Path, error: &anyhow::Error) -> Self {
+        let metadata_path = project_dir.join(".project-meta.yaml");
+        let location = error.chain()
+            .find_map(|cause| cause.downcast_ref::<serde_yaml::Error>())
+            .and_then(serde_yaml::Error::location);
+
+        Self {
+            project_dir: project_dir.to_path_buf(),
+            path: if metadata_path.exists() { metadata_path } else { project_dir.to_path_buf() },
+            message: format!("{:#}", error),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+        }
+    }
+}
+
+impl std::fmt::Display for ScanWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Projects found by a scan, plus the directories that were skipped.
+#[derive(Debug, Default)]
+pub struct ScanResult {
+    pub projects: Vec<Project>,
+    pub warnings: Vec<ScanWarning>,
+}
+
+impl ScanResult {
+    /// With `strict`, fail with a `parse` error when any directory was skipped.
+    pub fn ensure_complete(&self, strict: bool) -> Result<()> {
+        if !strict || self.warnings.is_empty() {
+            return Ok(());
+        }
+
+        let details: Vec<String> = self.warnings.iter().map(|warning| format!("  {}", warning)).collect();
+        Err(crate::utils::error::cli_error(
+            crate::utils::error::ErrorCode::Parse,
+            format!(
+                "{} project director{} could not be loaded (--strict):\n{}",
+                self.warnings.len(),
+                if self.warnings.len() == 1 { "y" } else { "ies" },
+                details.join("\n")
+            ),
+        ))
+    }
 }
 
 impl ProjectScanner {
     pub fn new<P: AsRef<Path>>(projects_path: P) -> Self {
         Self {
-            projects_path: projects_path.as_ref().to_path_buf(),
+            roots: vec![projects_path.as_ref().to_path_buf()],
+            max_depth: crate::config::ScanSettings::default().max_depth,
+            ignore: Vec::new(),
+            cache_dir: None,
+            archive: None,
         }
     }
 
-    pub async fn scan_projects(&self) -> Result<Vec<Project>> {
-        if !self.projects_path.exists() {
-            return Ok(Vec::new());
-        }
+    /// Scanner over the configured roots, depth limit and ignore globs.
+    pub fn from_settings(settings: &crate::config::Settings) -> Result<Self> {
+        let ignore = settings.scan.ignore.iter()
+            .map(|pattern| glob::Pattern::new(pattern)
+                .map_err(|e| crate::utils::error::invalid_argument(
+                    format!("Invalid scan ignore pattern '{}' in config: {}", pattern, e)
+                )))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut scanner = Self::new(settings.projects_path());
+        scanner.roots = settings.project_roots();
+        scanner.max_depth = settings.scan.max_depth.max(1);
+        scanner.ignore = ignore;
+        scanner.cache_dir = settings.scan.cache.then(|| settings.cache_path());
+        scanner.archive = Some(settings.archive_path());
+        Ok(scanner)
+    }
 
-        let mut projects = Vec::new();
-        let mut entries = tokio::fs::read_dir(&self.projects_path).await
-            .context("Failed to read projects directory")?;
-
-        while let Some(entry) = entries.next_entry().await? {
-            if entry.file_type().await?.is_dir() {
-                let dir_name = entry.file_name();
-                let dir_name_str = dir_name.to_string_lossy();
-                
-                // Skip template and hidden directories
-                if dir_name_str.starts_with('_') || dir_name_str.starts_with('.') {
-                    continue;
-                }
+    /// Also scan the archive folder, which is left out by default.
+    pub fn include_archived(mut self, include: bool) -> Self {
+        if let Some(archive) = self.archive.clone().filter(|archive| include && !self.roots.contains(archive)) {
+            self.roots.push(archive);
+        }
+        self
+    }
 
-                // Look for project metadata
-                let project_path = entry.path();
-                if let Ok(project) = self.load_project_from_directory(&project_path).await {
-                    projects.push(project);
-                }
-            }
+    /// Whether the project lives in the archive folder. Commands that
+    /// resolve relations scan with archived projects included and use this
+    /// to leave them out of what they show.
+    pub fn is_archived(&self, project: &Project) -> bool {
+        match (&self.archive, &project.path) {
+            (Some(archive), Some(dir)) => crate::core::is_in_archive(archive, dir),
+            _ => false,
         }
+    }
 
-        // Sort by update time (most recent first)
-        projects.sort_by(|a, b| b.updated.cmp(&a.updated));
-        
-        Ok(projects)
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
     }
 
-    pub async fn find_project_by_name(&self, name: &str) -> Result<Option<Project>> {
-        let projects = self.scan_projects().await?;
-        
-        // Try exact match first
-        for project in &projects {
-            if project.name == name {
-                return Ok(Some(project.clone()));
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Projects that loaded, most recently updated first. Use [`scan`] to
+    /// find out about directories that were skipped.
+    ///
+    /// [`scan`]: ProjectScanner::scan
+    pub async fn scan_projects(&self) -> Result<Vec<Project>> {
+        Ok(self.scan().await?.projects)
+    }
+
+    pub async fn scan(&self) -> Result<ScanResult> {
+        let mut result = ScanResult::default();
+        let dirs = self.project_dirs(&mut result.warnings);
+        let stamps: Vec<Stamp> = dirs.iter().map(|dir| Stamp::of(dir)).collect();
+        let index = self.cache_dir.as_deref().map(ProjectIndex::load);
+
+        // Reuse unchanged projects from the index and parse the rest concurrently
+        let mut loaded: Vec<Option<Result<Project>>> = Vec::with_capacity(dirs.len());
+        let mut tasks = tokio::task::JoinSet::new();
+        let loader = std::sync::Arc::new(self.clone());
+        for (i, (dir, stamp)) in dirs.iter().zip(&stamps).enumerate() {
+            match index.as_ref().and_then(|index| index.get(dir, stamp)) {
+                Some(cached) => loaded.push(Some(Ok(cached))),
+                None => {
+                    loaded.push(None);
+                    let loader = std::sync::Arc::clone(&loader);
+                    let dir = dir.clone();
+                    tasks.spawn(async move { (i, loader.load_project_from_directory(&dir).await) });
+                }
             }
         }
+        let parsed = tasks.len();
+        while let Some(joined) = tasks.join_next().await {
+            let (i, project) = joined.context("Project loader task failed")?;
+            loaded[i] = Some(project);
+        }
 
-        // Try case-insensitive match
-        for project in &projects {
-            if project.name.to_lowercase() == name.to_lowercase() {
-                return Ok(Some(project.clone()));
+        let mut fresh = ProjectIndex::new();
+        for ((dir, stamp), project) in dirs.iter().zip(stamps).zip(loaded) {
+            match project.expect("every project directory is loaded") {
+                Ok(project) => {
+                    fresh.entries.insert(dir.clone(), IndexEntry::new(stamp, project.clone()));
+                    result.projects.push(project);
+                }
+                Err(err) => result.warnings.push(ScanWarning::new(dir, &err)),
             }
         }
 
-        // Try partial match
-        for project in &projects {
-            if project.name.to_lowercase().contains(&name.to_lowercase()) {
-                return Ok(Some(project.clone()));
+        if let (Some(cache_dir), Some(index)) = (&self.cache_dir, &index) {
+            if parsed > 0 || fresh.entries.len() != index.entries.len() {
+                // The index only saves work; a read-only vault still scans fine
+                let _ = fresh.save(cache_dir);
             }
         }
 
-        Ok(None)
+        // Sort by update time (most recent first)
+        result.projects.sort_by_key(|project| std::cmp::Reverse(project.updated));
+        result.warnings.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(result)
+    }
+
+    pub async fn find_project_by_name(&self, name: &str) -> Result<Option<Project>> {
+        let projects = self.scan_projects().await?;
+        Ok(find_by_name(&projects, name).cloned())
     }
 
     pub async fn find_project_by_id(&self, id: &str) -> Result<Option<Project>> {
@@ -85,12 +214,75 @@ impl ProjectScanner {
         Ok(None)
     }
 
+    /// Project directories under every root, each listed once. Folders that
+    /// cannot be read are reported as warnings.
+    fn project_dirs(&self, warnings: &mut Vec<ScanWarning>) -> Vec<PathBuf> {
+        let mut found: Vec<PathBuf> = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+
+        for root in self.roots.iter().filter(|root| root.is_dir()) {
+            let mut walker = WalkDir::new(root)
+                .min_depth(1)
+                .max_depth(self.max_depth)
+                .sort_by_file_name()
+                .into_iter();
+
+            while let Some(entry) = walker.next() {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        let path = err.path().unwrap_or(root).to_path_buf();
+                        warnings.push(ScanWarning::new(&path, &anyhow::Error::new(err).context("Failed to read directory")));
+                        continue;
+                    }
+                };
+                if !entry.file_type().is_dir() {
+                    continue;
+                }
+
+                // Skip template, archive and hidden directories, and ignored ones
+                let name = entry.file_name().to_string_lossy();
+                let is_archive = self.archive.as_deref() == Some(entry.path());
+                if name.starts_with('_') || name.starts_with('.') || is_archive || self.is_ignored(root, entry.path()) {
+                    walker.skip_current_dir();
+                    continue;
+                }
+
+                if is_project_dir(entry.path()) {
+                    // Roots may overlap
+                    let canonical = entry.path().canonicalize().unwrap_or_else(|_| entry.path().to_path_buf());
+                    if seen.insert(canonical) {
+                        found.push(entry.path().to_path_buf());
+                    }
+                    // Folders inside a project are its own notes, not projects
+                    walker.skip_current_dir();
+                }
+            }
+        }
+
+        found
+    }
+
+    fn is_ignored(&self, root: &Path, path: &Path) -> bool {
+        let relative = path.strip_prefix(root).unwrap_or(path).to_string_lossy().replace('\\', "/");
+        let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+        self.ignore.iter().any(|pattern| {
+            if pattern.as_str().contains('/') {
+                pattern.matches(&relative)
+            } else {
+                pattern.matches(&name)
+            }
+        })
+    }
+
     async fn load_project_from_directory(&self, project_path: &Path) -> Result<Project> {
         // Look for metadata file
         let metadata_path = project_path.join(".project-meta.yaml");
         
         if metadata_path.exists() {
-            // Load from metadata file
+            // Load from metadata file, noting its state first so a later
+            // change is never mistaken for the one that was read
+            let metadata_state = FileState::of(&metadata_path);
             let content = tokio::fs::read_to_string(&metadata_path).await
                 .context("Failed to read project metadata")?;
             
@@ -98,6 +290,8 @@ impl ProjectScanner {
                 .context("Failed to parse project metadata")?;
             
             project.path = Some(project_path.to_path_buf());
+            project.last_edited = latest_note_edit(project_path);
+            project.metadata_state = metadata_state;
             return Ok(project);
         }
 
@@ -132,17 +326,26 @@ impl ProjectScanner {
         };
 
         // Try to read additional info from overview file
-        let overview_path = project_path.join("00_PROJECT-OVERVIEW.md");
-        let (description, status, priority, tags) = if overview_path.exists() {
-            self.parse_overview_file(&overview_path).await.unwrap_or_default()
+        let overview_path = project_path.join(OVERVIEW_FILE);
+        let overview = if overview_path.exists() {
+            parse_overview_file(&overview_path).await.ok()
         } else {
-            (None, crate::core::ProjectStatus::Idea, crate::core::Priority::Medium, Vec::new())
+            None
         };
+        let (description, status, priority, tags, due) = overview.unwrap_or_else(|| {
+            (None, crate::core::ProjectStatus::Idea, crate::core::Priority::Medium, Vec::new(), None)
+        });
 
-        // Create project with extracted information
+        // Create project with extracted information. The directory mtime only
+        // changes when entries are added or removed, so prefer note edits.
         let now = chrono::Utc::now();
-        let created = self.get_directory_creation_time(project_path).await.unwrap_or(now);
-        let updated = self.get_directory_modification_time(project_path).await.unwrap_or(now);
+        let last_edited = latest_note_edit(project_path);
+        let updated = match last_edited {
+            Some(edited) => edited,
+            None => self.get_directory_modification_time(project_path).await.unwrap_or(now),
+        };
+        // Copied or checked-out notes can predate their directory
+        let created = self.get_directory_creation_time(project_path).await.unwrap_or(now).min(updated);
 
         let project = Project {
             id: uuid::Uuid::new_v4().to_string(),
@@ -160,65 +363,20 @@ impl ProjectScanner {
                 timestamp: created,
                 note: Some("Project discovered".to_string()),
             }],
+            time_entries: Vec::new(),
+            depends_on: Vec::new(),
+            related: Vec::new(),
+            parent: None,
+            due,
+            archived_from: None,
             path: Some(project_path.to_path_buf()),
+            last_edited,
+            metadata_state: None,
         };
 
         Ok(project)
     }
 
-    async fn parse_overview_file(&self, overview_path: &Path) -> Result<(Option<String>, crate::core::ProjectStatus, crate::core::Priority, Vec<String>)> {
-        let content = tokio::fs::read_to_string(overview_path).await?;
-        
-        let mut description = None;
-        let mut status = crate::core::ProjectStatus::Idea;
-        let mut priority = crate::core::Priority::Medium;
-        let mut tags = Vec::new();
-
-        // Parse frontmatter if present
-        if content.starts_with("---") {
-            let end_marker = content[3..].find("---").map(|i| i + 6);
-            if let Some(end) = end_marker {
-                let frontmatter = &content[3..end-3];
-                
-                // Parse YAML frontmatter
-                if let Ok(metadata) = serde_yaml::from_str::<serde_yaml::Value>(frontmatter) {
-                    if let Some(map) = metadata.as_mapping() {
-                        // Extract description
-                        if let Some(desc) = map.get("description").and_then(|v| v.as_str()) {
-                            description = Some(desc.to_string());
-                        }
-
-                        // Extract status
-                        if let Some(status_str) = map.get("status").and_then(|v| v.as_str()) {
-                            status = parse_status(status_str);
-                        }
-
-                        // Extract priority
-                        if let Some(priority_str) = map.get("priority").and_then(|v| v.as_str()) {
-                            priority = parse_priority(priority_str);
-                        }
-
-                        // Extract tags
-                        if let Some(tags_array) = map.get("tags").and_then(|v| v.as_sequence()) {
-                            for tag in tags_array {
-                                if let Some(tag_str) = tag.as_str() {
-                                    tags.push(tag_str.to_string());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        // If no description in frontmatter, try to extract from content
-        if description.is_none() {
-            description = extract_description_from_content(&content);
-        }
-
-        Ok((description, status, priority, tags))
-    }
-
     async fn get_directory_creation_time(&self, path: &Path) -> Result<chrono::DateTime<chrono::Utc>> {
         let metadata = tokio::fs::metadata(path).await?;
         let created = metadata.created()
@@ -237,17 +395,132 @@ impl ProjectScanner {
     }
 }
 
-fn parse_status(status_str: &str) -> crate::core::ProjectStatus {
-    match status_str.to_lowercase().as_str() {
-        "idea" => crate::core::ProjectStatus::Idea,
-        "planning" => crate::core::ProjectStatus::Planning,
-        "development" => crate::core::ProjectStatus::Development,
-        "testing" => crate::core::ProjectStatus::Testing,
-        "completed" => crate::core::ProjectStatus::Completed,
-        "archived" => crate::core::ProjectStatus::Archived,
-        "cancelled" => crate::core::ProjectStatus::Cancelled,
-        _ => crate::core::ProjectStatus::Idea,
+fn is_project_dir(path: &Path) -> bool {
+    if path.join(".project-meta.yaml").is_file() || path.join(OVERVIEW_FILE).is_file() {
+        return true;
+    }
+
+    // Folders created by `opj new`: YYYY-MM-DD_Type_Name
+    let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    name.get(..10).is_some_and(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok())
+        && name[10..].starts_with('_')
+}
+
+/// Look up a project by ID, exact name, case-insensitive name or partial
+/// name, in that order.
+pub fn find_by_name<'a>(projects: &'a [Project], name: &str) -> Option<&'a Project> {
+    let lower_name = name.to_lowercase();
+
+    projects.iter().find(|p| p.id == name)
+        .or_else(|| projects.iter().find(|p| p.name == name))
+        .or_else(|| projects.iter().find(|p| p.name.to_lowercase() == lower_name))
+        .or_else(|| projects.iter().find(|p| p.name.to_lowercase().contains(&lower_name)))
+}
+
+type OverviewInfo = (
+    Option<String>,
+    crate::core::ProjectStatus,
+    crate::core::Priority,
+    Vec<String>,
+    Option<chrono::NaiveDate>,
+);
+
+async fn parse_overview_file(overview_path: &Path) -> Result<OverviewInfo> {
+    let content = tokio::fs::read_to_string(overview_path).await?;
+
+    let mut description = None;
+    let mut status = crate::core::ProjectStatus::Idea;
+    let mut priority = crate::core::Priority::Medium;
+    let mut tags = Vec::new();
+    let mut due = None;
+
+    // Parse frontmatter if present
+    if let Some(map) = parse_frontmatter(&content) {
+        // Extract description
+        if let Some(desc) = map.get("description").and_then(|v| v.as_str()) {
+            description = Some(desc.to_string());
+        }
+
+        // Extract status
+        if let Some(status_str) = map.get("status").and_then(|v| v.as_str()) {
+            status = parse_status(status_str);
+        }
+
+        // Extract priority
+        if let Some(priority_str) = map.get("priority").and_then(|v| v.as_str()) {
+            priority = parse_priority(priority_str);
+        }
+
+        // Extract tags
+        if let Some(tags_array) = map.get("tags").and_then(|v| v.as_sequence()) {
+            for tag in tags_array {
+                if let Some(tag_str) = tag.as_str() {
+                    tags.push(tag_str.to_string());
+                }
+            }
+        }
+
+        // Extract due date
+        if let Some(due_str) = map.get("due").and_then(|v| v.as_str()) {
+            due = chrono::NaiveDate::parse_from_str(due_str, "%Y-%m-%d").ok();
+        }
+    }
+
+    // If no description in frontmatter, try to extract from content
+    if description.is_none() {
+        description = extract_description_from_content(&content);
     }
+
+    Ok((description, status, priority, tags, due))
+}
+
+fn parse_frontmatter(content: &str) -> Option<serde_yaml::Mapping> {
+    let rest = content.strip_prefix("---")?;
+    let end = rest.find("---")?;
+    match serde_yaml::from_str::<serde_yaml::Value>(&rest[..end]).ok()? {
+        serde_yaml::Value::Mapping(map) => Some(map),
+        _ => None,
+    }
+}
+
+/// Frontmatter of a project's overview note, if the note exists and has one.
+pub fn overview_frontmatter(project_path: &Path) -> Option<serde_yaml::Mapping> {
+    let content = std::fs::read_to_string(project_path.join(OVERVIEW_FILE)).ok()?;
+    parse_frontmatter(&content)
+}
+
+/// Status written in the `status:` frontmatter of a project's overview note,
+/// if the note exists and has one.
+pub fn overview_status(project_path: &Path) -> Option<crate::core::ProjectStatus> {
+    overview_frontmatter(project_path)?.get("status")?.as_str()?.parse().ok()
+}
+
+/// `status` and `priority` from a project's overview frontmatter, if the note
+/// exists and has frontmatter.
+pub fn overview_fields(project_path: &Path) -> Option<crate::core::OverviewFields> {
+    let frontmatter = overview_frontmatter(project_path)?;
+    let field = |key: &str| frontmatter.get(key).and_then(|value| value.as_str());
+    Some(crate::core::OverviewFields {
+        status: field("status").and_then(|value| value.parse().ok()),
+        priority: field("priority").and_then(|value| value.parse().ok()),
+    })
+}
+
+/// Most recent modification time of any Markdown note in the project,
+/// ignoring hidden directories such as `.obsidian` or `.git`.
+pub fn latest_note_edit(project_path: &Path) -> Option<chrono::DateTime<chrono::Utc>> {
+    WalkDir::new(project_path)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+        .map(chrono::DateTime::from)
+}
+
+fn parse_status(status_str: &str) -> crate::core::ProjectStatus {
+    status_str.parse().unwrap_or(crate::core::ProjectStatus::Idea)
 }
 
 fn parse_priority(priority_str: &str) -> crate::core::Priority {
@@ -312,4 +585,168 @@ mod tests {
         assert!(projects.iter().any(|p| p.name == "CLI Tool"));
         assert!(projects.iter().any(|p| p.name == "Blog Post"));
     }
+
+    #[tokio::test]
+    async fn test_recursive_multi_root_scan() {
+        let vault = tempdir().unwrap();
+        let overview = |dir: &str| {
+            let path = vault.path().join(dir);
+            std::fs::create_dir_all(path.join("notes")).unwrap();
+            std::fs::write(path.join(OVERVIEW_FILE), "# Project\n").unwrap();
+        };
+        overview("Projects/2024/Old-Site");
+        overview("Projects/2025/Q1/Deep/Too-Deep");
+        overview("Projects/2025/Skip-Me");
+        overview("Areas/Work/Projects/Client-App");
+        std::fs::create_dir_all(vault.path().join("Projects/2025/Just-A-Folder")).unwrap();
+        std::fs::create_dir_all(vault.path().join("Projects/2025-06-18_Tool_Legacy")).unwrap();
+
+        let mut settings = crate::config::Settings::default();
+        settings.set_obsidian_path(vault.path());
+        settings.scan.roots = vec!["Projects".to_string(), "Areas/Work/Projects".to_string()];
+        settings.scan.ignore = vec!["Skip-*".to_string()];
+
+        let scanner = ProjectScanner::from_settings(&settings).unwrap();
+        let mut names: Vec<String> = scanner.scan_projects().await.unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        names.sort();
+
+        assert_eq!(names, vec!["Client-App", "Legacy", "Old-Site"]);
+
+        settings.scan.ignore = vec!["2024/**".to_string(), "[".to_string()];
+        assert!(ProjectScanner::from_settings(&settings).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scan_uses_and_refreshes_index() {
+        let vault = tempdir().unwrap();
+        let project_dir = vault.path().join("Projects").join("Cached");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let mut project = crate::core::fixture::project("Cached");
+        crate::core::save_project_metadata(&mut project, &project_dir).await.unwrap();
+
+        let mut settings = crate::config::Settings::default();
+        settings.set_obsidian_path(vault.path());
+        let scanner = ProjectScanner::from_settings(&settings).unwrap();
+
+        assert_eq!(scanner.scan_projects().await.unwrap()[0].name, "Cached");
+        let index = ProjectIndex::load(&settings.cache_path());
+        assert!(index.entries.contains_key(&project_dir));
+
+        // A cached entry is served while the files are unchanged
+        let mut stale = index;
+        stale.entries.get_mut(&project_dir).unwrap().project.name = "From index".to_string();
+        stale.save(&settings.cache_path()).unwrap();
+        let cached = scanner.scan_projects().await.unwrap();
+        assert_eq!(cached[0].name, "From index");
+        assert_eq!(cached[0].path.as_deref(), Some(project_dir.as_path()));
+
+        // ...and reparsed once they change
+        let mut renamed = project.clone();
+        renamed.name = "Renamed".to_string();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        crate::core::save_project_metadata(&mut renamed, &project_dir).await.unwrap();
+        assert_eq!(scanner.scan_projects().await.unwrap()[0].name, "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_archived_dependency_is_not_dangling() {
+        let vault = tempdir().unwrap();
+        let mut settings = crate::config::Settings::default();
+        settings.set_obsidian_path(vault.path());
+
+        let mut library = crate::core::fixture::project("Library");
+        let mut app = crate::core::fixture::project("App");
+        app.depends_on.push(library.id.clone());
+        let app_dir = settings.projects_path().join("App");
+        let library_dir = settings.archive_path().join("2025").join("Library");
+        for (project, dir) in [(&mut app, &app_dir), (&mut library, &library_dir)] {
+            std::fs::create_dir_all(dir).unwrap();
+            crate::core::save_project_metadata(project, dir).await.unwrap();
+        }
+
+        let scanner = ProjectScanner::from_settings(&settings).unwrap().include_archived(true);
+        let projects = scanner.scan_projects().await.unwrap();
+        let archived: Vec<&str> = projects.iter().filter(|p| scanner.is_archived(p)).map(|p| p.name.as_str()).collect();
+        assert_eq!(archived, vec!["Library"]);
+
+        let issues = crate::core::diagnose(&crate::core::VaultState {
+            projects: &projects,
+            failures: Vec::new(),
+            overviews: Default::default(),
+            templates_path: settings.templates_path(),
+            default_type: crate::core::ProjectType::Tool,
+        });
+        assert!(!issues.iter().any(|issue| issue.kind == crate::core::IssueKind::DanglingRelation));
+    }
+
+    #[tokio::test]
+    async fn test_cached_scan_notices_note_edits() {
+        use std::time::SystemTime;
+        let vault = tempdir().unwrap();
+        let project_dir = vault.path().join("Projects").join("2025-06-18_Tool_Notes");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(project_dir.join(OVERVIEW_FILE), "# Notes\n").unwrap();
+        let note = project_dir.join("Ideas.md");
+        std::fs::write(&note, "first\n").unwrap();
+        let set_mtime = |time: SystemTime| std::fs::File::options().write(true).open(&note).unwrap().set_modified(time).unwrap();
+        let earlier = SystemTime::now() - std::time::Duration::from_secs(3600);
+        set_mtime(earlier);
+
+        let mut settings = crate::config::Settings::default();
+        settings.set_obsidian_path(vault.path());
+        let scanner = ProjectScanner::from_settings(&settings).unwrap();
+        let first = scanner.scan_projects().await.unwrap();
+        assert!(first[0].last_edited.unwrap() > chrono::DateTime::<chrono::Utc>::from(earlier));
+
+        // Editing a note in place leaves the overview and the folder alone
+        let later = SystemTime::now() + std::time::Duration::from_secs(3600);
+        set_mtime(later);
+        let second = scanner.scan_projects().await.unwrap();
+        assert_eq!(second[0].last_edited, Some(chrono::DateTime::from(later)));
+        assert_eq!(second[0].updated, chrono::DateTime::<chrono::Utc>::from(later));
+    }
+
+    #[tokio::test]
+    async fn test_scan_reports_invalid_metadata() {
+        let temp_dir = tempdir().unwrap();
+        let broken = temp_dir.path().join("Broken");
+        tokio::fs::create_dir_all(&broken).await.unwrap();
+        tokio::fs::write(broken.join(".project-meta.yaml"), "id: x\nname: [oops\n").await.unwrap();
+        tokio::fs::create_dir_all(temp_dir.path().join("2025-06-18_Tool_Fine")).await.unwrap();
+
+        let scan = ProjectScanner::new(temp_dir.path()).scan().await.unwrap();
+
+        assert_eq!(scan.projects.len(), 1);
+        assert_eq!(scan.warnings.len(), 1);
+        let warning = &scan.warnings[0];
+        assert_eq!(warning.path, broken.join(".project-meta.yaml"));
+        assert!(warning.message.starts_with("Failed to parse project metadata: "));
+        assert_eq!((warning.line, warning.column), (Some(2), Some(7)));
+    }
+
+    #[tokio::test]
+    async fn test_overview_frontmatter_and_note_edits() {
+        let temp_dir = tempdir().unwrap();
+        let project_path = temp_dir.path().join("2025-06-18_Tool_Dated");
+        tokio::fs::create_dir_all(project_path.join(".obsidian")).await.unwrap();
+        tokio::fs::write(
+            project_path.join(OVERVIEW_FILE),
+            "---\nstatus: testing\ndue: 2025-07-01\n---\n# Dated\n",
+        ).await.unwrap();
+        tokio::fs::write(project_path.join(".obsidian").join("notes.md"), "hidden").await.unwrap();
+
+        let scanner = ProjectScanner::new(temp_dir.path());
+        let project = scanner.parse_project_from_structure(&project_path).await.unwrap();
+
+        assert_eq!(project.status, crate::core::ProjectStatus::Testing);
+        assert_eq!(project.due, chrono::NaiveDate::from_ymd_opt(2025, 7, 1));
+        assert_eq!(overview_status(&project_path), Some(crate::core::ProjectStatus::Testing));
+
+        let overview_mtime = std::fs::metadata(project_path.join(OVERVIEW_FILE)).unwrap().modified().unwrap();
+        assert_eq!(project.last_edited, Some(chrono::DateTime::from(overview_mtime)));
+        assert!(project.created <= project.updated);
+    }
 }
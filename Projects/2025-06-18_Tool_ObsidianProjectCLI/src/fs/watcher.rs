@@ -0,0 +1,101 @@
+// Turns file system events into per-project changes for `opj watch`
+
+use notify::event::{EventKind, ModifyKind};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils::markdown::OVERVIEW_FILE;
+
+/// What changed inside one project during a batch of events.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectChange {
+    /// The overview note was written
+    pub overview: bool,
+    /// Any Markdown note was written, including the overview
+    pub notes: bool,
+}
+
+/// Whether an event can change what a scan sees. Reads and attribute
+/// changes are left out, so the watcher's own scans do not wake it up.
+pub fn is_content_change(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Create(_) | EventKind::Remove(_) => true,
+        EventKind::Modify(modify) => !matches!(modify, ModifyKind::Metadata(_)),
+        _ => false,
+    }
+}
+
+/// Paths the watcher does not react to: anything outside the roots, inside
+/// hidden folders such as `.obsidian` or `.opj`, or one of `ignored`.
+pub fn is_ignored(path: &Path, roots: &[PathBuf], ignored: &[PathBuf]) -> bool {
+    if ignored.iter().any(|ignored| ignored == path) {
+        return true;
+    }
+    let Some(relative) = roots.iter().find_map(|root| path.strip_prefix(root).ok()) else {
+        return true;
+    };
+    // Hidden files such as `.project-meta.yaml` count, hidden folders do not
+    let mut folders = relative.parent().into_iter().flat_map(Path::components);
+    folders.any(|component| component.as_os_str().to_string_lossy().starts_with('.'))
+}
+
+/// Group changed paths by the project directory they are in.
+pub fn project_changes(paths: &[PathBuf], project_dirs: &[PathBuf]) -> BTreeMap<PathBuf, ProjectChange> {
+    let mut changes: BTreeMap<PathBuf, ProjectChange> = BTreeMap::new();
+
+    for path in paths {
+        if path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+        let Some(dir) = project_dirs.iter()
+            .filter(|dir| path.starts_with(dir))
+            .max_by_key(|dir| dir.components().count()) else { continue };
+
+        let change = changes.entry(dir.clone()).or_default();
+        change.notes = true;
+        if *path == dir.join(OVERVIEW_FILE) {
+            change.overview = true;
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind, DataChange, MetadataKind};
+
+    #[test]
+    fn test_event_filtering() {
+        assert!(is_content_change(&EventKind::Create(CreateKind::File)));
+        assert!(is_content_change(&EventKind::Modify(ModifyKind::Data(DataChange::Content))));
+        assert!(!is_content_change(&EventKind::Modify(ModifyKind::Metadata(MetadataKind::AccessTime))));
+        assert!(!is_content_change(&EventKind::Access(AccessKind::Read)));
+
+        let roots = vec![PathBuf::from("/vault/Projects")];
+        let dashboard = PathBuf::from("/vault/Projects/_Dashboard.md");
+        let ignored = |path: &str| is_ignored(Path::new(path), &roots, std::slice::from_ref(&dashboard));
+        assert!(!ignored("/vault/Projects/App/notes/todo.md"));
+        assert!(!ignored("/vault/Projects/App/.project-meta.yaml"));
+        assert!(ignored("/vault/Projects/App/.obsidian/workspace.json"));
+        assert!(ignored("/vault/Projects/_Dashboard.md"));
+        assert!(ignored("/vault/Areas/other.md"));
+    }
+
+    #[test]
+    fn test_project_changes() {
+        let dirs = vec![PathBuf::from("/p/App"), PathBuf::from("/p/Site")];
+        let paths: Vec<PathBuf> = [
+            "/p/App/00_PROJECT-OVERVIEW.md",
+            "/p/App/.project-meta.yaml",
+            "/p/Site/notes/ideas.md",
+            "/p/loose.md",
+        ].iter().map(PathBuf::from).collect();
+
+        let changes = project_changes(&paths, &dirs);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[&dirs[0]], ProjectChange { overview: true, notes: true });
+        assert_eq!(changes[&dirs[1]], ProjectChange { overview: false, notes: true });
+    }
+}
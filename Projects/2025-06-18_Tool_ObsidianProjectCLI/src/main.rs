@@ -1,18 +1,52 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{error::ErrorKind, ArgMatches, CommandFactory, FromArgMatches};
 
 mod cli;
 mod core;
 mod fs;
 mod config;
 mod utils;
+mod tui;
 
-use cli::{Cli, Commands};
+use cli::{BackupCommand, Cli, Commands, ExportCommand, ImportCommand};
+use utils::{error, output};
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+async fn main() {
+    let json_requested = requests_json(&std::env::args().collect::<Vec<_>>());
 
+    let matches = match Cli::command().try_get_matches() {
+        Ok(matches) => matches,
+        Err(e) if json_requested && !is_informational(e.kind()) => {
+            let rendered = e.render().to_string();
+            let message = rendered.lines().next().unwrap_or_default().trim_start_matches("error: ");
+            let error = error::cli_error(error::ErrorCode::Usage, message);
+            print_json_error(None, &error);
+            std::process::exit(2);
+        }
+        Err(e) => e.exit(),
+    };
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    output::set_json_output(cli.json);
+    output::set_command_name(command_path(&matches));
+    core::set_force(cli.force);
+
+    let result = run(cli).await;
+    if let Err(e) = fs::history::finish() {
+        eprintln!("Warning: could not save the undo history: {:#}", e);
+    }
+    if let Err(error) = result {
+        if output::json_output() {
+            print_json_error(Some(output::command_name()), &error);
+        } else {
+            eprintln!("Error: {:?}", error);
+        }
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
     match cli.command {
         Commands::New { 
             name, 
@@ -21,7 +55,9 @@ async fn main() -> Result<()> {
             description,
             template,
             no_git,
-            interactive 
+            interactive,
+            parent,
+            due
         } => {
             cli::commands::new::execute(
                 name, 
@@ -30,24 +66,44 @@ async fn main() -> Result<()> {
                 description, 
                 template, 
                 no_git,
-                interactive
+                interactive,
+                parent,
+                due
             ).await?;
         }
         Commands::List { 
             status, 
             project_type, 
             priority, 
+            query,
             sort, 
-            format 
+            format,
+            columns,
+            template,
+            tree,
+            strict,
+            include_archived
         } => {
             cli::commands::list::execute(
                 status, 
                 project_type, 
                 priority, 
+                query,
                 sort, 
-                format
+                format,
+                columns,
+                template,
+                tree,
+                strict,
+                include_archived
             ).await?;
         }
+        Commands::Archive { project, note } => {
+            cli::commands::archive::archive(project, note, cli.dry_run).await?;
+        }
+        Commands::Unarchive { project, status } => {
+            cli::commands::archive::unarchive(project, status, cli.dry_run).await?;
+        }
         Commands::Status { 
             project, 
             set_status, 
@@ -61,8 +117,8 @@ async fn main() -> Result<()> {
                 log
             ).await?;
         }
-        Commands::Info { project } => {
-            cli::commands::info::execute(project).await?;
+        Commands::Info { project, format, columns } => {
+            cli::commands::info::execute(project, format, columns).await?;
         }
         Commands::Template { subcommand } => {
             cli::commands::template::execute(subcommand).await?;
@@ -73,10 +129,108 @@ async fn main() -> Result<()> {
         Commands::Config { key, value } => {
             cli::commands::config::execute(key, value).await?;
         }
-        Commands::Stats => {
-            cli::commands::stats::execute().await?;
+        Commands::Stats { format, query, trends, interval, last, strict, include_archived } => {
+            cli::commands::stats::execute(format, query, trends, interval, last, strict, include_archived).await?;
+        }
+        Commands::Search { query, format, columns, strict, include_archived } => {
+            cli::commands::search::execute(query.join(" "), format, columns, strict, include_archived).await?;
+        }
+        Commands::Query { subcommand } => {
+            cli::commands::query::execute(subcommand).await?;
+        }
+        Commands::Tasks { subcommand } => {
+            cli::commands::tasks::execute(subcommand).await?;
+        }
+        Commands::Start { project, note } => {
+            cli::commands::time::start(project, note).await?;
+        }
+        Commands::Stop { note } => {
+            cli::commands::time::stop(note).await?;
+        }
+        Commands::Time { project, subcommand } => {
+            cli::commands::time::execute(project, subcommand).await?;
+        }
+        Commands::Link { from, to, kind, remove } => {
+            cli::commands::link::execute(from, to, kind, remove).await?;
+        }
+        Commands::Graph { format, status, project_type, output, dashboard } => {
+            cli::commands::graph::execute(format, status, project_type, output, dashboard).await?;
+        }
+        Commands::Dashboard => {
+            cli::commands::dashboard::execute().await?;
+        }
+        Commands::Watch { dashboard, debounce } => {
+            cli::commands::watch::execute(dashboard, debounce).await?;
+        }
+        Commands::Attention { kind, query } => {
+            cli::commands::attention::execute(kind, query).await?;
+        }
+        Commands::Doctor { fix } => {
+            cli::commands::doctor::execute(fix).await?;
+        }
+        Commands::Backup { subcommand: Some(BackupCommand::List), .. } => {
+            cli::commands::backup::list().await?;
+        }
+        Commands::Backup { subcommand: None, project, out } => {
+            cli::commands::backup::backup(project, out).await?;
+        }
+        Commands::Restore { archive, project, on_conflict } => {
+            cli::commands::backup::restore(archive, project, on_conflict, cli.dry_run).await?;
+        }
+        Commands::History { limit } => {
+            cli::commands::history::history(limit).await?;
+        }
+        Commands::Undo { count } => {
+            cli::commands::history::undo(count, cli.force, cli.dry_run).await?;
+        }
+        Commands::Cache { subcommand } => {
+            cli::commands::cache::execute(subcommand).await?;
+        }
+        Commands::Report { period, from, to, format, output, note, query } => {
+            cli::commands::report::execute(period, from, to, format, output, note, query).await?;
+        }
+        Commands::Tui => {
+            cli::commands::tui::execute().await?;
+        }
+        Commands::Board { status, interactive, query } => {
+            cli::commands::board::execute(status, interactive, query).await?;
+        }
+        Commands::Export { subcommand: ExportCommand::Kanban { out, status } } => {
+            cli::commands::kanban::export(out, status).await?;
+        }
+        Commands::Import { subcommand: ImportCommand::Kanban { file } } => {
+            cli::commands::kanban::import(file, cli.dry_run).await?;
         }
     }
 
     Ok(())
 }
+
+fn print_json_error(command: Option<&str>, error: &anyhow::Error) {
+    let envelope = output::error_envelope(command, error);
+    println!("{}", serde_json::to_string_pretty(&envelope).unwrap_or_default());
+}
+
+/// Subcommand names joined by spaces, e.g. `tasks list`.
+fn command_path(matches: &ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub_matches)) = current.subcommand() {
+        names.push(name);
+        current = sub_matches;
+    }
+    names.join(" ")
+}
+
+/// Whether `--json` was given, so that argument errors can be reported as
+/// JSON before parsing has succeeded.
+fn requests_json(args: &[String]) -> bool {
+    args.iter().skip(1).take_while(|arg| *arg != "--").any(|arg| arg == "--json")
+}
+
+fn is_informational(kind: ErrorKind) -> bool {
+    matches!(
+        kind,
+        ErrorKind::DisplayHelp | ErrorKind::DisplayVersion | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+    )
+}
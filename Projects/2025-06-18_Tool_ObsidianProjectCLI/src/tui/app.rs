@@ -0,0 +1,285 @@
+// TUI application state and key handling
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::path::PathBuf;
+
+use crate::core::{Project, ProjectStatus, ALL_STATUSES};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    Normal,
+    Filter,
+    SelectStatus { selected: usize },
+    AddNote { input: String },
+}
+
+/// Side effects requested by a key press, carried out by the event loop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    None,
+    Quit,
+    Refresh,
+    SetStatus(ProjectStatus),
+    CyclePriority,
+    AddNote(String),
+    OpenEditor,
+}
+
+pub struct App {
+    pub projects: Vec<Project>,
+    pub filter: String,
+    pub mode: Mode,
+    pub message: Option<String>,
+    selected_id: Option<String>,
+    /// Projects without metadata get a new ID on every scan, so the folder
+    /// identifies the selection across rescans
+    selected_path: Option<PathBuf>,
+}
+
+impl App {
+    pub fn new(projects: Vec<Project>) -> Self {
+        let mut app = Self {
+            projects: Vec::new(),
+            filter: String::new(),
+            mode: Mode::Normal,
+            message: None,
+            selected_id: None,
+            selected_path: None,
+        };
+        app.set_projects(projects);
+        app
+    }
+
+    /// Replace the project list (e.g. after a rescan), keeping the current
+    /// selection when the selected project still exists.
+    pub fn set_projects(&mut self, mut projects: Vec<Project>) {
+        projects.sort_by_key(|p| std::cmp::Reverse(p.updated));
+        self.projects = projects;
+        self.refilter();
+    }
+
+    /// Projects matching the current filter, in display order.
+    pub fn visible(&self) -> Vec<&Project> {
+        let filter = self.filter.to_lowercase();
+        self.projects.iter()
+            .filter(|p| filter.is_empty() || matches_filter(p, &filter))
+            .collect()
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.visible().iter().position(|p| self.is_selected(p))
+    }
+
+    pub fn selected(&self) -> Option<&Project> {
+        self.projects.iter().find(|p| self.is_selected(p))
+    }
+
+    pub fn selected_mut(&mut self) -> Option<&mut Project> {
+        let index = self.projects.iter().position(|p| self.is_selected(p))?;
+        self.projects.get_mut(index)
+    }
+
+    fn is_selected(&self, project: &Project) -> bool {
+        self.selected_id.as_ref() == Some(&project.id)
+            || (project.path.is_some() && self.selected_path == project.path)
+    }
+
+    fn select(&mut self, key: Option<(String, Option<PathBuf>)>) {
+        (self.selected_id, self.selected_path) = match key {
+            Some((id, path)) => (Some(id), path),
+            None => (None, None),
+        };
+    }
+
+    fn move_selection(&mut self, offset: isize) {
+        let visible = self.visible();
+        if visible.is_empty() {
+            self.select(None);
+            return;
+        }
+
+        let current = self.selected_index().unwrap_or(0) as isize;
+        let next = (current + offset).clamp(0, visible.len() as isize - 1) as usize;
+        let next = selection_key(visible[next]);
+        self.select(Some(next));
+    }
+
+    /// Fall back to the first visible project when the selection is hidden.
+    fn refilter(&mut self) {
+        let visible = self.visible();
+        // Picks up the ID the selected project has after this scan
+        let key = visible.iter()
+            .find(|p| self.is_selected(p))
+            .or(visible.first())
+            .map(|p| selection_key(p));
+        self.select(key);
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+
+        match self.mode.clone() {
+            Mode::Normal => self.handle_normal_key(key),
+            Mode::Filter => {
+                match key.code {
+                    KeyCode::Esc => {
+                        self.filter.clear();
+                        self.mode = Mode::Normal;
+                    }
+                    KeyCode::Enter => self.mode = Mode::Normal,
+                    KeyCode::Backspace => {
+                        self.filter.pop();
+                    }
+                    KeyCode::Char(c) => self.filter.push(c),
+                    _ => {}
+                }
+                self.refilter();
+                Action::None
+            }
+            Mode::SelectStatus { selected } => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.mode = Mode::Normal;
+                    Action::None
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.mode = Mode::SelectStatus { selected: selected.saturating_sub(1) };
+                    Action::None
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.mode = Mode::SelectStatus { selected: (selected + 1).min(ALL_STATUSES.len() - 1) };
+                    Action::None
+                }
+                KeyCode::Enter => {
+                    self.mode = Mode::Normal;
+                    Action::SetStatus(ALL_STATUSES[selected].clone())
+                }
+                _ => Action::None,
+            },
+            Mode::AddNote { mut input } => match key.code {
+                KeyCode::Esc => {
+                    self.mode = Mode::Normal;
+                    Action::None
+                }
+                KeyCode::Enter => {
+                    self.mode = Mode::Normal;
+                    let note = input.trim().to_string();
+                    if note.is_empty() { Action::None } else { Action::AddNote(note) }
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                    self.mode = Mode::AddNote { input };
+                    Action::None
+                }
+                KeyCode::Char(c) => {
+                    input.push(c);
+                    self.mode = Mode::AddNote { input };
+                    Action::None
+                }
+                _ => Action::None,
+            },
+        }
+    }
+
+    fn handle_normal_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::Home | KeyCode::Char('g') => self.move_selection(-(self.projects.len() as isize)),
+            KeyCode::End | KeyCode::Char('G') => self.move_selection(self.projects.len() as isize),
+            KeyCode::Char('/') => self.mode = Mode::Filter,
+            KeyCode::Char('r') => return Action::Refresh,
+            KeyCode::Char('s') if self.selected().is_some() => {
+                let current = self.selected()
+                    .and_then(|p| ALL_STATUSES.iter().position(|s| *s == p.status))
+                    .unwrap_or(0);
+                self.mode = Mode::SelectStatus { selected: current };
+            }
+            KeyCode::Char('p') if self.selected().is_some() => return Action::CyclePriority,
+            KeyCode::Char('n') if self.selected().is_some() => {
+                self.mode = Mode::AddNote { input: String::new() };
+            }
+            KeyCode::Char('e') | KeyCode::Enter if self.selected().is_some() => return Action::OpenEditor,
+            _ => {}
+        }
+        Action::None
+    }
+}
+
+fn selection_key(project: &Project) -> (String, Option<PathBuf>) {
+    (project.id.clone(), project.path.clone())
+}
+
+fn matches_filter(project: &Project, filter: &str) -> bool {
+    project.name.to_lowercase().contains(filter)
+        || project.project_type.to_string().to_lowercase().contains(filter)
+        || project.status.to_string().to_lowercase().contains(filter)
+        || project.tags.iter().any(|tag| tag.to_lowercase().contains(filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_filter_keeps_valid_selection() {
+        let mut app = App::new(vec![project("Alpha"), project("Beta"), project("Gamma")]);
+        assert_eq!(app.visible().len(), 3);
+
+        app.handle_key(key(KeyCode::Char('/')));
+        for c in "bet".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.visible().len(), 1);
+        assert_eq!(app.selected().unwrap().name, "Beta");
+
+        app.handle_key(key(KeyCode::Esc));
+        assert!(app.filter.is_empty());
+        assert_eq!(app.selected().unwrap().name, "Beta");
+    }
+
+    #[test]
+    fn test_selection_survives_rescan_with_new_ids() {
+        let scan = || ["Alpha", "Beta"].map(|name| {
+            let mut project = project(name).with_dir(PathBuf::from("/vault/Projects").join(name));
+            project.id = uuid::Uuid::new_v4().to_string();
+            project
+        }).to_vec();
+
+        let mut app = App::new(scan());
+        app.handle_key(key(KeyCode::Down));
+        let selected = app.selected().unwrap().name.clone();
+
+        // Projects without metadata come back with fresh IDs
+        app.set_projects(scan());
+        assert_eq!(app.selected().unwrap().name, selected);
+    }
+
+    #[test]
+    fn test_status_selection_and_notes() {
+        let mut app = App::new(vec![project("Alpha")]);
+
+        app.handle_key(key(KeyCode::Char('s')));
+        assert_eq!(app.mode, Mode::SelectStatus { selected: 0 });
+        app.handle_key(key(KeyCode::Down));
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.handle_key(key(KeyCode::Enter)), Action::SetStatus(ProjectStatus::Development));
+
+        app.handle_key(key(KeyCode::Char('n')));
+        for c in "hi".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.handle_key(key(KeyCode::Enter)), Action::AddNote("hi".to_string()));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.handle_key(key(KeyCode::Char('q'))), Action::Quit);
+    }
+}
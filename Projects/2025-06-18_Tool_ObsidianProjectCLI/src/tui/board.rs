@@ -0,0 +1,316 @@
+// Interactive kanban board (`opj board --interactive`)
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+use std::time::Instant;
+
+use crate::cli::commands::status::apply_status_change;
+use crate::config::Settings;
+use crate::core::{self, Project, ProjectQuery, ProjectStatus};
+use crate::fs::scanner::ProjectScanner;
+use super::ui::{status_style, type_emoji};
+use super::{enter_terminal, leave_terminal, vault_snapshot, Tui, REFRESH_INTERVAL, TICK_RATE};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardAction {
+    None,
+    Quit,
+    Refresh,
+    /// Move the selected card to the given column's status
+    Move { project_id: String, status: ProjectStatus },
+}
+
+pub struct BoardApp {
+    pub columns: Vec<ProjectStatus>,
+    pub projects: Vec<Project>,
+    pub column: usize,
+    pub row: usize,
+    pub message: Option<String>,
+}
+
+impl BoardApp {
+    pub fn new(columns: Vec<ProjectStatus>, projects: Vec<Project>) -> Self {
+        let mut app = Self { columns, projects, column: 0, row: 0, message: None };
+        app.clamp_row();
+        app
+    }
+
+    pub fn set_projects(&mut self, projects: Vec<Project>) {
+        let selected = self.selected().map(|p| p.id.clone());
+        self.projects = projects;
+        if let Some(id) = selected {
+            self.select(&id);
+        }
+        self.clamp_row();
+    }
+
+    pub fn cards(&self, column: usize) -> Vec<&Project> {
+        core::build_board(&self.columns[column..=column], &self.projects)
+            .pop()
+            .map(|column| column.projects)
+            .unwrap_or_default()
+    }
+
+    pub fn selected(&self) -> Option<&Project> {
+        self.cards(self.column).get(self.row).copied()
+    }
+
+    /// Put the cursor on the given project, wherever it is on the board.
+    pub fn select(&mut self, project_id: &str) {
+        for column in 0..self.columns.len() {
+            if let Some(row) = self.cards(column).iter().position(|p| p.id == project_id) {
+                self.column = column;
+                self.row = row;
+                return;
+            }
+        }
+    }
+
+    fn clamp_row(&mut self) {
+        let len = self.cards(self.column).len();
+        self.row = self.row.min(len.saturating_sub(1));
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> BoardAction {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return BoardAction::Quit;
+        }
+
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return BoardAction::Quit,
+            KeyCode::Char('r') => return BoardAction::Refresh,
+            KeyCode::Left if shift => return self.move_card(-1),
+            KeyCode::Right if shift => return self.move_card(1),
+            KeyCode::Char('H') | KeyCode::Char('<') => return self.move_card(-1),
+            KeyCode::Char('L') | KeyCode::Char('>') => return self.move_card(1),
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.column = self.column.saturating_sub(1);
+                self.clamp_row();
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.column = (self.column + 1).min(self.columns.len() - 1);
+                self.clamp_row();
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.row = self.row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.row += 1;
+                self.clamp_row();
+            }
+            _ => {}
+        }
+        BoardAction::None
+    }
+
+    fn move_card(&self, offset: isize) -> BoardAction {
+        let Some(project) = self.selected() else {
+            return BoardAction::None;
+        };
+
+        let target = self.column as isize + offset;
+        if target < 0 || target as usize >= self.columns.len() {
+            return BoardAction::None;
+        }
+
+        BoardAction::Move {
+            project_id: project.id.clone(),
+            status: self.columns[target as usize].clone(),
+        }
+    }
+}
+
+pub async fn run(settings: Settings, columns: Vec<ProjectStatus>, query: ProjectQuery) -> Result<()> {
+    if columns.is_empty() {
+        return Err(anyhow::anyhow!("The board needs at least one status column"));
+    }
+
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let mut app = BoardApp::new(columns, scan(&scanner, &query).await?);
+
+    let mut terminal = enter_terminal()?;
+    let result = event_loop(&mut terminal, &mut app, &scanner, &query).await;
+    leave_terminal(&mut terminal)?;
+
+    result
+}
+
+async fn event_loop(
+    terminal: &mut Tui,
+    app: &mut BoardApp,
+    scanner: &ProjectScanner,
+    query: &ProjectQuery,
+) -> Result<()> {
+    let mut snapshot = vault_snapshot(scanner);
+    let mut last_check = Instant::now();
+
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        if event::poll(TICK_RATE)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                app.message = None;
+
+                match app.handle_key(key) {
+                    BoardAction::None => {}
+                    BoardAction::Quit => return Ok(()),
+                    BoardAction::Refresh => {
+                        app.set_projects(scan(scanner, query).await?);
+                        app.message = Some("🔄 Refreshed".to_string());
+                    }
+                    BoardAction::Move { project_id, status } => {
+                        app.message = Some(match move_card(app, &project_id, status).await {
+                            Ok(message) => message,
+                            Err(e) => format!("❌ {}", e),
+                        });
+                    }
+                }
+            }
+        }
+
+        if last_check.elapsed() >= REFRESH_INTERVAL {
+            last_check = Instant::now();
+            let current = vault_snapshot(scanner);
+            if current != snapshot {
+                snapshot = current;
+                app.set_projects(scan(scanner, query).await?);
+            }
+        }
+    }
+}
+
+async fn scan(scanner: &ProjectScanner, query: &ProjectQuery) -> Result<Vec<Project>> {
+    let mut projects = scanner.scan_projects().await?;
+    query.apply(&mut projects);
+    Ok(projects)
+}
+
+/// Change the card's status through the same path as `opj status --set`.
+async fn move_card(app: &mut BoardApp, project_id: &str, status: ProjectStatus) -> Result<String> {
+    let project = app.projects.iter_mut()
+        .find(|p| p.id == project_id)
+        .ok_or_else(|| anyhow::anyhow!("Project no longer exists"))?;
+
+    let old_status = project.status.clone();
+    apply_status_change(project, status, Some("Moved on board".to_string())).await?;
+    crate::fs::history::finish()?;
+    let message = format!("✅ \"{}\": {} → {}", project.name, old_status, project.status);
+
+    app.select(project_id);
+    Ok(message)
+}
+
+fn draw(frame: &mut Frame, app: &BoardApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(frame.area());
+
+    let constraints = vec![Constraint::Ratio(1, app.columns.len() as u32); app.columns.len()];
+    let lanes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(chunks[0]);
+
+    for (index, status) in app.columns.iter().enumerate() {
+        let cards = app.cards(index);
+        let is_active = index == app.column;
+
+        let items: Vec<ListItem> = cards.iter()
+            .map(|project| {
+                let mut meta = vec![
+                    Span::styled(format!("  {}", project.priority), priority_style(&project.priority)),
+                    Span::styled(format!(" · {}d", project.days_since_activity()), Style::default().fg(Color::DarkGray)),
+                ];
+                if core::is_stale(project) {
+                    meta.push(Span::styled(" stale", Style::default().fg(Color::LightRed)));
+                }
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::raw(format!("{} ", type_emoji(&project.project_type))),
+                        Span::styled(project.name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                    ]),
+                    Line::from(meta),
+                    Line::default(),
+                ])
+            })
+            .collect();
+
+        let border_style = if is_active {
+            status_style(status)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let list = List::new(items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .title(Span::styled(format!(" {} ({}) ", status, cards.len()), status_style(status))))
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        let selected = if is_active && !cards.is_empty() { Some(app.row) } else { None };
+        let mut state = ListState::default().with_selected(selected);
+        frame.render_stateful_widget(list, lanes[index], &mut state);
+    }
+
+    let footer = match &app.message {
+        Some(message) => Line::from(Span::styled(message.clone(), Style::default().fg(Color::Green))),
+        None => Line::from(Span::styled(
+            "←/→ column  ↑/↓ card  H/L or Shift+←/→ move card  r refresh  q quit",
+            Style::default().fg(Color::DarkGray),
+        )),
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+fn priority_style(priority: &core::Priority) -> Style {
+    match priority {
+        core::Priority::High => Style::default().fg(Color::LightRed),
+        core::Priority::Medium => Style::default().fg(Color::LightYellow),
+        core::Priority::Low => Style::default().fg(Color::LightBlue),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_board_navigation_and_moves() {
+        let columns = vec![ProjectStatus::Planning, ProjectStatus::Development, ProjectStatus::Testing];
+        let api = project("api").with_status(ProjectStatus::Development);
+        let mut app = BoardApp::new(columns, vec![project("web").with_status(ProjectStatus::Planning), api.clone()]);
+
+        assert_eq!(app.selected().unwrap().name, "web");
+        app.handle_key(key(KeyCode::Right));
+        assert_eq!(app.selected().unwrap().name, "api");
+
+        assert_eq!(
+            app.handle_key(key(KeyCode::Char('L'))),
+            BoardAction::Move { project_id: api.id.clone(), status: ProjectStatus::Testing }
+        );
+        assert_eq!(
+            app.handle_key(KeyEvent::new(KeyCode::Left, KeyModifiers::SHIFT)),
+            BoardAction::Move { project_id: api.id.clone(), status: ProjectStatus::Planning }
+        );
+
+        // Empty column: nothing selected, nothing to move
+        app.handle_key(key(KeyCode::Right));
+        assert!(app.selected().is_none());
+        assert_eq!(app.handle_key(key(KeyCode::Char('H'))), BoardAction::None);
+        assert_eq!(app.handle_key(key(KeyCode::Char('q'))), BoardAction::Quit);
+    }
+}
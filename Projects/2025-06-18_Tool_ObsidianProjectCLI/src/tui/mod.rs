@@ -0,0 +1,197 @@
+// Full-screen terminal UI (`opj tui`)
+
+pub mod app;
+pub mod board;
+pub mod ui;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use std::io::Stdout;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
+use walkdir::WalkDir;
+
+use crate::cli::commands::status::apply_status_change;
+use crate::config::Settings;
+use crate::core;
+use crate::fs::operations::{read_file_to_string, write_string_to_file};
+use crate::fs::scanner::ProjectScanner;
+use crate::utils::markdown::{append_log_entry, DEV_LOG_FILE};
+use app::{Action, App, Mode};
+
+type Tui = Terminal<CrosstermBackend<Stdout>>;
+
+const TICK_RATE: Duration = Duration::from_millis(250);
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+pub async fn run(settings: Settings) -> Result<()> {
+    let scanner = ProjectScanner::from_settings(&settings)?;
+    let mut app = App::new(scanner.scan_projects().await?);
+
+    let mut terminal = enter_terminal()?;
+    let result = event_loop(&mut terminal, &mut app, &settings, &scanner).await;
+    leave_terminal(&mut terminal)?;
+
+    result
+}
+
+async fn event_loop(terminal: &mut Tui, app: &mut App, settings: &Settings, scanner: &ProjectScanner) -> Result<()> {
+    let mut snapshot = vault_snapshot(scanner);
+    let mut last_check = Instant::now();
+
+    loop {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if event::poll(TICK_RATE)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                if app.mode == Mode::Normal {
+                    app.message = None;
+                }
+
+                match app.handle_key(key) {
+                    Action::None => {}
+                    Action::Quit => return Ok(()),
+                    Action::Refresh => {
+                        app.set_projects(scanner.scan_projects().await?);
+                        app.message = Some("🔄 Refreshed".to_string());
+                    }
+                    action => {
+                        let result = perform(action, app, settings, terminal).await;
+                        // Each action is its own entry for `opj undo`
+                        let result = result.and_then(|message| crate::fs::history::finish().map(|_| message));
+                        app.message = Some(match result {
+                            Ok(message) => message,
+                            Err(e) => format!("❌ {}", e),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Pick up edits made outside the TUI (Obsidian, editor, other opj commands)
+        if last_check.elapsed() >= REFRESH_INTERVAL {
+            last_check = Instant::now();
+            let current = vault_snapshot(scanner);
+            if current != snapshot {
+                snapshot = current;
+                app.set_projects(scanner.scan_projects().await?);
+            }
+        }
+    }
+}
+
+async fn perform(action: Action, app: &mut App, settings: &Settings, terminal: &mut Tui) -> Result<String> {
+    let project = app.selected_mut()
+        .ok_or_else(|| anyhow::anyhow!("No project selected"))?;
+
+    match action {
+        Action::SetStatus(status) => {
+            if project.status == status {
+                return Ok(format!("ℹ️ \"{}\" is already {}", project.name, status));
+            }
+            apply_status_change(project, status, None).await?;
+            Ok(format!("✅ \"{}\" is now {}", project.name, project.status))
+        }
+        Action::CyclePriority => {
+            project.update_priority(project.priority.next());
+            if let Some(project_path) = project.path.clone() {
+                core::save_project_metadata(project, &project_path).await?;
+            }
+            Ok(format!("✅ \"{}\" priority set to {}", project.name, project.priority))
+        }
+        Action::AddNote(note) => {
+            let project_path = project.path.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Project '{}' has no directory", project.name))?;
+            let log_path = project_path.join(DEV_LOG_FILE);
+
+            let content = if log_path.exists() {
+                read_file_to_string(&log_path).await?
+            } else {
+                format!("# {} - Development Log\n", project.name)
+            };
+            let now = Local::now();
+            let updated = append_log_entry(
+                &content,
+                &now.format(&settings.date_format).to_string(),
+                &now.format("%H:%M").to_string(),
+                &note,
+            );
+            write_string_to_file(&log_path, &updated).await?;
+
+            Ok(format!("📝 Note added to {}", DEV_LOG_FILE))
+        }
+        Action::OpenEditor => {
+            let project_path = project.path.clone()
+                .ok_or_else(|| anyhow::anyhow!("Project '{}' has no directory", project.name))?;
+            let name = project.name.clone();
+            open_in_editor(settings, &project_path, terminal)?;
+            Ok(format!("✏️ Opened \"{}\" in editor", name))
+        }
+        Action::None | Action::Quit | Action::Refresh => Ok(String::new()),
+    }
+}
+
+/// Suspend the TUI, run the configured editor on the project directory and
+/// restore the screen afterwards.
+fn open_in_editor(settings: &Settings, project_path: &Path, terminal: &mut Tui) -> Result<()> {
+    let editor = settings.editor.clone()
+        .or_else(|| std::env::var("EDITOR").ok())
+        .ok_or_else(|| anyhow::anyhow!("No editor configured. Set 'editor' in the config file or $EDITOR"))?;
+
+    let mut parts = editor.split_whitespace();
+    let program = parts.next()
+        .ok_or_else(|| anyhow::anyhow!("No editor configured"))?;
+
+    leave_terminal(terminal)?;
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(project_path)
+        .status();
+    *terminal = enter_terminal()?;
+
+    let status = status.with_context(|| format!("Failed to launch editor '{}'", program))?;
+    if !status.success() {
+        return Err(anyhow::anyhow!("Editor '{}' exited with {}", program, status));
+    }
+
+    Ok(())
+}
+
+fn enter_terminal() -> Result<Tui> {
+    enable_raw_mode().context("Failed to enable raw mode")?;
+    let mut stdout = std::io::stdout();
+    execute!(stdout, EnterAlternateScreen).context("Failed to enter alternate screen")?;
+    Terminal::new(CrosstermBackend::new(stdout)).context("Failed to initialize terminal")
+}
+
+fn leave_terminal(terminal: &mut Tui) -> Result<()> {
+    disable_raw_mode().context("Failed to disable raw mode")?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen).context("Failed to leave alternate screen")?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+/// Cheap change detector for the projects directory: the number of notes and
+/// metadata files plus the newest modification time among them.
+fn vault_snapshot(scanner: &ProjectScanner) -> (usize, Option<SystemTime>) {
+    scanner.roots().iter()
+        .flat_map(|root| WalkDir::new(root).max_depth(scanner.max_depth() + 1))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            name.ends_with(".md") || name == ".project-meta.yaml"
+        })
+        .fold((0, None), |(count, newest), entry| {
+            let modified = entry.metadata().ok().and_then(|m| m.modified().ok());
+            (count + 1, newest.max(modified))
+        })
+}
@@ -0,0 +1,225 @@
+// TUI rendering
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::core::{Priority, ProjectStatus, ProjectType, ALL_STATUSES};
+use crate::tui::app::{App, Mode};
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(frame.area());
+
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(chunks[0]);
+
+    draw_project_list(frame, app, panes[0]);
+    draw_project_details(frame, app, panes[1]);
+    draw_footer(frame, app, chunks[1]);
+
+    if let Mode::SelectStatus { selected } = app.mode {
+        draw_status_popup(frame, selected);
+    }
+}
+
+fn draw_project_list(frame: &mut Frame, app: &App, area: Rect) {
+    let visible = app.visible();
+    let items: Vec<ListItem> = visible.iter()
+        .map(|project| {
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", type_emoji(&project.project_type))),
+                Span::styled(project.name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" "),
+                Span::styled(project.status.to_string(), status_style(&project.status)),
+            ]))
+        })
+        .collect();
+
+    let title = if app.filter.is_empty() {
+        format!(" 📊 Projects ({}) ", visible.len())
+    } else {
+        format!(" 📊 Projects ({}/{}) — /{} ", visible.len(), app.projects.len(), app.filter)
+    };
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default().with_selected(app.selected_index());
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Mirrors the sections printed by `opj info`.
+fn draw_project_details(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(" 📋 Details ");
+
+    let Some(project) = app.selected() else {
+        let empty = Paragraph::new("No projects found matching the criteria.")
+            .style(Style::default().fg(Color::Yellow))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    };
+
+    let heading = |text: &str| Line::from(Span::styled(
+        text.to_string(),
+        Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD),
+    ));
+    let field = |label: &str, value: Span<'static>| Line::from(vec![
+        Span::raw(format!("  {}: ", label)),
+        value,
+    ]);
+
+    let mut lines = vec![
+        heading("📋 Basic Information"),
+        field("Name", Span::styled(project.name.clone(), Style::default().fg(Color::White))),
+        field("Type", Span::styled(
+            format!("{} {}", type_emoji(&project.project_type), project.project_type),
+            Style::default().fg(Color::Cyan),
+        )),
+        field("Status", Span::styled(project.status.to_string(), status_style(&project.status))),
+        field("Priority", Span::styled(project.priority.to_string(), priority_style(&project.priority))),
+        field("Progress", Span::styled(
+            format!("{:.0}%", crate::core::rollup_progress(project, &app.projects)),
+            Style::default().fg(Color::Green),
+        )),
+        Line::default(),
+    ];
+
+    if let Some(description) = &project.description {
+        lines.push(heading("📄 Description"));
+        lines.push(Line::from(Span::styled(format!("  {}", description), Style::default().fg(Color::Cyan))));
+        lines.push(Line::default());
+    }
+
+    if !project.technologies.is_empty() {
+        lines.push(heading("🔧 Technologies"));
+        for tech in &project.technologies {
+            lines.push(Line::from(Span::styled(format!("  • {}", tech), Style::default().fg(Color::Yellow))));
+        }
+        lines.push(Line::default());
+    }
+
+    if !project.tags.is_empty() {
+        lines.push(heading("🏷️  Tags"));
+        let tags = project.tags.iter()
+            .map(|tag| format!("#{}", tag))
+            .collect::<Vec<_>>()
+            .join(" ");
+        lines.push(Line::from(Span::styled(format!("  {}", tags), Style::default().fg(Color::Magenta))));
+        lines.push(Line::default());
+    }
+
+    lines.push(heading("⏰ Timeline"));
+    lines.push(field("Created", Span::raw(project.created.format("%Y-%m-%d %H:%M UTC").to_string())));
+    lines.push(field("Last Updated", Span::raw(project.updated.format("%Y-%m-%d %H:%M UTC").to_string())));
+    lines.push(field("Days Active", Span::raw(project.days_since_creation().to_string())));
+    lines.push(field("Days Since Update", Span::raw(project.days_since_update().to_string())));
+
+    if let Some(note) = project.status_history.last().and_then(|entry| entry.note.clone()) {
+        lines.push(Line::default());
+        lines.push(heading("📈 Last Status Note"));
+        lines.push(Line::from(Span::styled(format!("  {}", note), Style::default().fg(Color::Cyan))));
+    }
+
+    let details = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(details, area);
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let line = match &app.mode {
+        Mode::Filter => Line::from(vec![
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw(app.filter.clone()),
+            Span::styled("▏", Style::default().fg(Color::Yellow)),
+            Span::styled("  Enter: keep  Esc: clear", Style::default().fg(Color::DarkGray)),
+        ]),
+        Mode::AddNote { input } => Line::from(vec![
+            Span::styled("Note: ", Style::default().fg(Color::Yellow)),
+            Span::raw(input.clone()),
+            Span::styled("▏", Style::default().fg(Color::Yellow)),
+            Span::styled("  Enter: save to log  Esc: cancel", Style::default().fg(Color::DarkGray)),
+        ]),
+        Mode::SelectStatus { .. } => Line::from(Span::styled(
+            "↑/↓ choose status  Enter: apply  Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Mode::Normal => match &app.message {
+            Some(message) => Line::from(Span::styled(message.clone(), Style::default().fg(Color::Green))),
+            None => Line::from(Span::styled(
+                "↑/↓ move  / filter  s status  p priority  n note  e edit  r refresh  q quit",
+                Style::default().fg(Color::DarkGray),
+            )),
+        },
+    };
+
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn draw_status_popup(frame: &mut Frame, selected: usize) {
+    let area = centered_rect(30, ALL_STATUSES.len() as u16 + 2, frame.area());
+    let items: Vec<ListItem> = ALL_STATUSES.iter()
+        .map(|status| ListItem::new(Span::styled(status.to_string(), status_style(status))))
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Set Status "))
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+pub fn status_style(status: &ProjectStatus) -> Style {
+    let style = Style::default();
+    match status {
+        ProjectStatus::Idea => style.fg(Color::LightBlue),
+        ProjectStatus::Planning => style.fg(Color::LightCyan),
+        ProjectStatus::Development => style.fg(Color::LightGreen),
+        ProjectStatus::Testing => style.fg(Color::LightYellow),
+        ProjectStatus::Completed => style.fg(Color::LightGreen).add_modifier(Modifier::BOLD),
+        ProjectStatus::Archived => style.fg(Color::DarkGray),
+        ProjectStatus::Cancelled => style.fg(Color::LightRed),
+    }
+}
+
+fn priority_style(priority: &Priority) -> Style {
+    match priority {
+        Priority::High => Style::default().fg(Color::LightRed),
+        Priority::Medium => Style::default().fg(Color::LightYellow),
+        Priority::Low => Style::default().fg(Color::LightBlue),
+    }
+}
+
+pub fn type_emoji(project_type: &ProjectType) -> &'static str {
+    match project_type {
+        ProjectType::WebApp => "🌐",
+        ProjectType::Tool => "🛠️",
+        ProjectType::Content => "📝",
+        ProjectType::Api => "🔌",
+        ProjectType::Custom(_) => "📦",
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 pub fn format_relative_time(datetime: &DateTime<Utc>) -> String {
     let now = Utc::now();
@@ -51,6 +51,63 @@ pub fn format_duration_days(days: i64) -> String {
     }
 }
 
+pub fn format_duration_hm(duration: &Duration) -> String {
+    let total_minutes = duration.num_minutes().max(0);
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+
+    if hours == 0 {
+        format!("{}m", minutes)
+    } else if minutes == 0 {
+        format!("{}h", hours)
+    } else {
+        format!("{}h {}m", hours, minutes)
+    }
+}
+
+/// Parse durations such as "1h30m", "2h", "45m", "1.5h" or a bare number
+/// of minutes.
+pub fn parse_duration(duration_str: &str) -> Result<Duration, String> {
+    let input = duration_str.trim().to_lowercase().replace(' ', "");
+    if input.is_empty() {
+        return Err("Duration is empty".to_string());
+    }
+
+    let too_long = || format!("Duration is too long: {}", duration_str);
+    if let Ok(minutes) = input.parse::<i64>() {
+        return Duration::try_minutes(minutes).ok_or_else(too_long);
+    }
+
+    let mut total_minutes = 0.0;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+
+        let value: f64 = number.parse()
+            .map_err(|_| format!("Invalid duration: {}", duration_str))?;
+        number.clear();
+
+        total_minutes += match c {
+            'h' => value * 60.0,
+            'm' => value,
+            _ => return Err(format!("Invalid duration unit '{}' in {}", c, duration_str)),
+        };
+    }
+
+    if !number.is_empty() {
+        return Err(format!("Missing unit after '{}' in {}", number, duration_str));
+    }
+
+    let total_minutes = total_minutes.round();
+    if !total_minutes.is_finite() || total_minutes.abs() >= i64::MAX as f64 {
+        return Err(too_long());
+    }
+    Duration::try_minutes(total_minutes as i64).ok_or_else(too_long)
+}
+
 pub fn parse_date_string(date_str: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
     // Try different date formats
     let formats = [
@@ -77,7 +134,6 @@ pub fn parse_date_string(date_str: &str) -> Result<DateTime<Utc>, chrono::ParseE
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
 
     #[test]
     fn test_format_relative_time() {
@@ -108,6 +164,30 @@ mod tests {
         assert_eq!(format_duration_days(60), "2 months");
     }
 
+    #[test]
+    fn test_format_duration_hm() {
+        assert_eq!(format_duration_hm(&Duration::minutes(45)), "45m");
+        assert_eq!(format_duration_hm(&Duration::minutes(120)), "2h");
+        assert_eq!(format_duration_hm(&Duration::minutes(90)), "1h 30m");
+        assert_eq!(format_duration_hm(&Duration::minutes(-5)), "0m");
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::minutes(120));
+        assert_eq!(parse_duration("45m").unwrap(), Duration::minutes(45));
+        assert_eq!(parse_duration("1.5h").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_duration("25").unwrap(), Duration::minutes(25));
+        assert_eq!(parse_duration("1h 15m").unwrap(), Duration::minutes(75));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("3d").is_err());
+        assert!(parse_duration("1h30").is_err());
+        assert!(parse_duration("9999999999999999").is_err());
+        assert!(parse_duration("99999999999999h").is_err());
+        assert!(parse_duration(&format!("{}h", "9".repeat(400))).is_err());
+    }
+
     #[test]
     fn test_parse_date_string() {
         let date_str = "2025-06-18";
@@ -0,0 +1,98 @@
+// Error codes reported in `--json` error envelopes
+
+use serde::Serialize;
+
+/// Stable, machine-readable error categories. New codes may be added, but
+/// existing ones keep their meaning within a schema version.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// A project, task, note or saved query does not exist
+    NotFound,
+    /// An argument or query could not be understood
+    InvalidArgument,
+    /// The change would conflict with existing data (cycles, duplicates)
+    Conflict,
+    /// The command cannot run in this mode (e.g. interactive UIs in JSON mode)
+    Unsupported,
+    /// Reading or writing a file failed
+    Io,
+    /// A configuration, metadata or note file could not be parsed
+    Parse,
+    /// Command-line usage error
+    Usage,
+    Internal,
+}
+
+/// An error with an explicit code, for failures callers are expected to
+/// handle. Other errors are classified by [`error_code`].
+#[derive(Debug)]
+pub struct CliError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub fn cli_error(code: ErrorCode, message: impl Into<String>) -> anyhow::Error {
+    anyhow::Error::new(CliError { code, message: message.into() })
+}
+
+pub fn not_found(message: impl Into<String>) -> anyhow::Error {
+    cli_error(ErrorCode::NotFound, message)
+}
+
+pub fn invalid_argument(message: impl Into<String>) -> anyhow::Error {
+    cli_error(ErrorCode::InvalidArgument, message)
+}
+
+pub fn conflict(message: impl Into<String>) -> anyhow::Error {
+    cli_error(ErrorCode::Conflict, message)
+}
+
+/// Code for any error: an explicit [`CliError`] anywhere in the chain wins,
+/// then I/O and parse failures are recognised by their source type.
+pub fn error_code(error: &anyhow::Error) -> ErrorCode {
+    for cause in error.chain() {
+        if let Some(cli_error) = cause.downcast_ref::<CliError>() {
+            return cli_error.code;
+        }
+    }
+
+    for cause in error.chain() {
+        if cause.is::<std::io::Error>() {
+            return ErrorCode::Io;
+        }
+        if cause.is::<serde_yaml::Error>() || cause.is::<serde_json::Error>() {
+            return ErrorCode::Parse;
+        }
+    }
+
+    ErrorCode::Internal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(error_code(&not_found("Project 'x' not found")), ErrorCode::NotFound);
+
+        let wrapped = Err::<(), _>(conflict("cycle")).context("Failed to link").unwrap_err();
+        assert_eq!(error_code(&wrapped), ErrorCode::Conflict);
+        assert_eq!(wrapped.root_cause().to_string(), "cycle");
+
+        let io = Err::<(), _>(std::io::Error::other("disk full")).context("Failed to write").unwrap_err();
+        assert_eq!(error_code(&io), ErrorCode::Io);
+
+        assert_eq!(error_code(&anyhow::anyhow!("boom")), ErrorCode::Internal);
+    }
+}
@@ -0,0 +1,404 @@
+// Machine-readable output of projects and statistics
+
+use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+
+use crate::cli::{Column, OutputFormat};
+use crate::core::{Project, ProjectStats, Trends};
+use crate::utils::template::TemplateProcessor;
+
+/// Columns used when `--columns` is not given, matching the table view.
+pub const DEFAULT_COLUMNS: [Column; 5] = [
+    Column::Name,
+    Column::Type,
+    Column::Status,
+    Column::Priority,
+    Column::Updated,
+];
+
+/// Every column, in declaration order.
+pub fn all_columns() -> Vec<Column> {
+    Column::value_variants().to_vec()
+}
+
+/// Header name of a column, as spelled on the command line.
+pub fn column_name(column: &Column) -> String {
+    column.to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Human-readable column title for table headers.
+pub fn column_title(column: &Column) -> &'static str {
+    match column {
+        Column::Id => "ID",
+        Column::Name => "Name",
+        Column::Type => "Type",
+        Column::Status => "Status",
+        Column::Priority => "Priority",
+        Column::Path => "Path",
+        Column::Tags => "Tags",
+        Column::Technologies => "Technologies",
+        Column::Created => "Created",
+        Column::Updated => "Updated",
+        Column::Due => "Due",
+        Column::Progress => "Progress",
+        Column::DaysSinceUpdate => "Days Since Update",
+        Column::Description => "Description",
+    }
+}
+
+pub fn column_value(project: &Project, column: &Column) -> Value {
+    match column {
+        Column::Id => Value::from(project.id.clone()),
+        Column::Name => Value::from(project.name.clone()),
+        Column::Type => Value::from(project.project_type.to_string()),
+        Column::Status => Value::from(project.status.to_string()),
+        Column::Priority => Value::from(project.priority.to_string()),
+        Column::Path => project.path.as_ref()
+            .map(|path| Value::from(path.display().to_string()))
+            .unwrap_or(Value::Null),
+        Column::Tags => Value::from(project.tags.clone()),
+        Column::Technologies => Value::from(project.technologies.clone()),
+        Column::Created => Value::from(timestamp(&project.created)),
+        Column::Updated => Value::from(timestamp(&project.updated)),
+        Column::Due => project.due
+            .map(|due| Value::from(due.format("%Y-%m-%d").to_string()))
+            .unwrap_or(Value::Null),
+        Column::Progress => Value::from(project.get_progress_percentage() as f64),
+        Column::DaysSinceUpdate => Value::from(project.days_since_update()),
+        Column::Description => project.description.clone()
+            .map(Value::from)
+            .unwrap_or(Value::Null),
+    }
+}
+
+/// The `project` object of the JSON schema: every column under its
+/// `--columns` name, plus relation ids.
+pub fn project_object(project: &Project) -> Value {
+    let mut object: Map<String, Value> = all_columns().iter()
+        .map(|column| (column_name(column), column_value(project, column)))
+        .collect();
+    object.insert("parent".to_string(), project.parent.clone().map(Value::from).unwrap_or(Value::Null));
+    object.insert("depends-on".to_string(), Value::from(project.depends_on.clone()));
+    object.insert("related".to_string(), Value::from(project.related.clone()));
+    Value::Object(object)
+}
+
+/// Short `{id, name, status}` reference used for related projects.
+pub fn project_ref(project: &Project) -> Value {
+    json!({
+        "id": project.id,
+        "name": project.name,
+        "status": project.status.to_string(),
+    })
+}
+
+pub fn status_history(project: &Project) -> Value {
+    project.status_history.iter()
+        .map(|entry| json!({
+            "status": entry.status.to_string(),
+            "at": timestamp(&entry.timestamp),
+            "note": entry.note,
+        }))
+        .collect()
+}
+
+pub fn stats_object(stats: &ProjectStats) -> Value {
+    let counts = |pairs: Vec<(String, usize)>| -> Map<String, Value> {
+        pairs.into_iter().map(|(key, count)| (key, Value::from(count))).collect()
+    };
+
+    json!({
+        "total": stats.total,
+        "active": stats.active,
+        "completed": stats.completed,
+        "completion_rate": round_tenths(stats.completion_rate()),
+        "stale": stats.stale,
+        "average_age_days": round_tenths(stats.average_age_days),
+        "time_logged_minutes": stats.total_time.num_minutes(),
+        "by_status": counts(stats.by_status.iter().map(|(s, c)| (s.to_string(), *c)).collect()),
+        "by_type": counts(stats.by_type.clone()),
+        "by_priority": counts(stats.by_priority.iter().map(|(p, c)| (p.to_string(), *c)).collect()),
+    })
+}
+
+pub fn trends_object(trends: &Trends) -> Value {
+    let days = |duration: chrono::Duration| round_tenths(duration.num_minutes() as f32 / (60.0 * 24.0));
+
+    json!({
+        "interval": match trends.interval {
+            crate::core::Interval::Week => "week",
+            crate::core::Interval::Month => "month",
+        },
+        "buckets": Records::from_trends(trends).objects(),
+        "cycle_time": {
+            "count": trends.cycle_times.len(),
+            "average_days": trends.average_cycle_time().map(days),
+            "median_days": trends.median_cycle_time().map(days),
+            "projects": trends.cycle_times.iter()
+                .map(|cycle| json!({
+                    "project": project_ref(cycle.project),
+                    "started": timestamp(&cycle.started),
+                    "completed": timestamp(&cycle.completed),
+                    "days": days(cycle.duration()),
+                }))
+                .collect::<Vec<_>>(),
+        },
+        "time_in_status": trends.time_in_status.iter()
+            .map(|time| json!({
+                "status": time.status.to_string(),
+                "total_days": days(time.total),
+                "average_days": days(time.average()),
+                "projects": time.projects,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub fn round_tenths(value: f32) -> f64 {
+    (value as f64 * 10.0).round() / 10.0
+}
+
+/// RFC 3339 UTC timestamp with second precision, as used throughout the
+/// machine-readable formats.
+pub fn timestamp(at: &DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// A header row plus typed cells, rendered to any output format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Records {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Records {
+    pub fn from_projects(projects: &[Project], columns: &[Column]) -> Self {
+        Self {
+            headers: columns.iter().map(column_name).collect(),
+            rows: projects.iter()
+                .map(|project| columns.iter().map(|column| column_value(project, column)).collect())
+                .collect(),
+        }
+    }
+
+    /// One `metric`/`value` row per aggregate; breakdowns are keyed as
+    /// `status.<name>`, `type.<name>` and `priority.<name>`.
+    pub fn from_stats(stats: &ProjectStats) -> Self {
+        let mut rows = vec![
+            vec![Value::from("total"), Value::from(stats.total)],
+            vec![Value::from("active"), Value::from(stats.active)],
+            vec![Value::from("completed"), Value::from(stats.completed)],
+            vec![Value::from("completion_rate"), Value::from(stats.completion_rate() as f64)],
+            vec![Value::from("stale"), Value::from(stats.stale)],
+            vec![Value::from("average_age_days"), Value::from(stats.average_age_days as f64)],
+            vec![Value::from("time_logged_minutes"), Value::from(stats.total_time.num_minutes())],
+        ];
+        for (status, count) in &stats.by_status {
+            rows.push(vec![Value::from(format!("status.{}", status)), Value::from(*count)]);
+        }
+        for (type_name, count) in &stats.by_type {
+            rows.push(vec![Value::from(format!("type.{}", type_name)), Value::from(*count)]);
+        }
+        for (priority, count) in &stats.by_priority {
+            rows.push(vec![Value::from(format!("priority.{}", priority)), Value::from(*count)]);
+        }
+
+        Self { headers: vec!["metric".to_string(), "value".to_string()], rows }
+    }
+
+    /// One row per week or month of `opj stats --trends`.
+    pub fn from_trends(trends: &Trends) -> Self {
+        Self {
+            headers: ["period", "start", "end", "completed", "started", "wip"]
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+            rows: trends.buckets.iter()
+                .map(|bucket| vec![
+                    Value::from(trends.interval.label(bucket.start)),
+                    Value::from(bucket.start.format("%Y-%m-%d").to_string()),
+                    Value::from(bucket.end.format("%Y-%m-%d").to_string()),
+                    Value::from(bucket.completed),
+                    Value::from(bucket.started),
+                    Value::from(bucket.wip),
+                ])
+                .collect(),
+        }
+    }
+
+    /// One object per row, keyed by header.
+    pub fn objects(&self) -> Vec<Value> {
+        self.rows.iter()
+            .map(|row| {
+                let object: Map<String, Value> = self.headers.iter().cloned().zip(row.iter().cloned()).collect();
+                Value::Object(object)
+            })
+            .collect()
+    }
+}
+
+/// Render records in a machine-readable format. Table output is handled by
+/// the commands themselves.
+pub fn render_records(records: &Records, format: &OutputFormat) -> Result<String> {
+    let rendered = match format {
+        OutputFormat::Table => {
+            return Err(anyhow::anyhow!("Table output is not supported here"));
+        }
+        OutputFormat::Json => format!("{}\n", serde_json::to_string_pretty(&records.objects())?),
+        OutputFormat::Yaml => serde_yaml::to_string(&records.objects())?,
+        OutputFormat::Ndjson => {
+            let mut out = String::new();
+            for object in records.objects() {
+                out.push_str(&serde_json::to_string(&object)?);
+                out.push('\n');
+            }
+            out
+        }
+        OutputFormat::Csv => render_delimited(records, ',', escape_csv),
+        OutputFormat::Tsv => render_delimited(records, '\t', escape_tsv),
+        OutputFormat::Markdown => {
+            let mut out = markdown_row(records.headers.iter().map(|h| h.as_str()));
+            out.push_str(&markdown_row(records.headers.iter().map(|_| "---")));
+            for row in &records.rows {
+                let cells: Vec<String> = row.iter().map(|cell| escape_markdown(&cell_text(cell))).collect();
+                out.push_str(&markdown_row(cells.iter().map(|c| c.as_str())));
+            }
+            out
+        }
+    };
+
+    Ok(rendered)
+}
+
+/// Fill a `--template` string for one project. Every column is available
+/// under its `--columns` name (`{{name}}`, `{{days-since-update}}`, ...)
+/// alongside the usual template variables, and `\t`/`\n` escapes are
+/// expanded so tab-separated templates can be passed from the shell.
+pub fn render_project_template(template: &str, project: &Project) -> String {
+    let mut processor = TemplateProcessor::new(project);
+    for column in all_columns() {
+        processor.add_variable(column_name(&column), cell_text(&column_value(project, &column)));
+    }
+
+    let template = template.replace("\\t", "\t").replace("\\n", "\n");
+    processor.process_string(&template)
+}
+
+/// Plain-text form of a cell: lists are joined with ", " and nulls are empty.
+pub fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(cell_text).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn render_delimited(records: &Records, delimiter: char, escape: fn(&str) -> String) -> String {
+    let separator = delimiter.to_string();
+    let mut out = records.headers.iter()
+        .map(|header| escape(header))
+        .collect::<Vec<_>>()
+        .join(&separator);
+    out.push('\n');
+
+    for row in &records.rows {
+        out.push_str(&row.iter().map(|cell| escape(&cell_text(cell))).collect::<Vec<_>>().join(&separator));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn escape_csv(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn escape_tsv(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+fn markdown_row<'a>(cells: impl Iterator<Item = &'a str>) -> String {
+    format!("| {} |\n", cells.collect::<Vec<_>>().join(" | "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::{project, ProjectFixture};
+
+    fn sample() -> Vec<Project> {
+        let mut project = project("Web, \"v2\"")
+            .with_type(crate::core::ProjectType::WebApp)
+            .with_priority(crate::core::Priority::High)
+            .with_tags(&["rust", "cli"]);
+        project.description = Some("A | B".to_string());
+        vec![project]
+    }
+
+    #[test]
+    fn test_column_names() {
+        assert_eq!(column_name(&Column::DaysSinceUpdate), "days-since-update");
+        assert_eq!(all_columns().len(), 14);
+    }
+
+    #[test]
+    fn test_render_delimited() {
+        let records = Records::from_projects(&sample(), &[Column::Name, Column::Tags, Column::Progress]);
+
+        let csv = render_records(&records, &OutputFormat::Csv).unwrap();
+        assert_eq!(csv, "name,tags,progress\n\"Web, \"\"v2\"\"\",\"rust, cli\",10.0\n");
+
+        let tsv = render_records(&records, &OutputFormat::Tsv).unwrap();
+        assert_eq!(tsv, "name\ttags\tprogress\nWeb, \"v2\"\trust, cli\t10.0\n");
+    }
+
+    #[test]
+    fn test_render_markdown_and_ndjson() {
+        let records = Records::from_projects(&sample(), &[Column::Priority, Column::Description, Column::Tags]);
+
+        let markdown = render_records(&records, &OutputFormat::Markdown).unwrap();
+        assert_eq!(markdown, "| priority | description | tags |\n| --- | --- | --- |\n| High | A \\| B | rust, cli |\n");
+
+        let ndjson = render_records(&records, &OutputFormat::Ndjson).unwrap();
+        assert_eq!(ndjson, "{\"priority\":\"High\",\"description\":\"A | B\",\"tags\":[\"rust\",\"cli\"]}\n");
+    }
+
+    #[test]
+    fn test_render_project_template() {
+        let project = &sample()[0];
+        assert_eq!(
+            render_project_template("{{name}}\\t{{status}}\\t{{tags}} {{project_type}} {{unknown}}", project),
+            "Web, \"v2\"\tIdea\trust, cli Web-App {{unknown}}"
+        );
+    }
+
+    #[test]
+    fn test_project_object() {
+        let object = project_object(&sample()[0]);
+        assert_eq!(object["name"], Value::from("Web, \"v2\""));
+        assert_eq!(object["days-since-update"], Value::from(0));
+        assert_eq!(object["parent"], Value::Null);
+        assert_eq!(object.as_object().unwrap().len(), all_columns().len() + 3);
+    }
+
+    #[test]
+    fn test_stats_records() {
+        let records = Records::from_stats(&ProjectStats::compute(&sample()));
+        assert_eq!(records.headers, vec!["metric", "value"]);
+        assert!(records.rows.contains(&vec![Value::from("type.Web-App"), Value::from(1)]));
+        assert!(render_records(&records, &OutputFormat::Table).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use std::path::Path;
+
+use crate::core::Project;
+
+pub const OVERVIEW_FILE: &str = "00_PROJECT-OVERVIEW.md";
+pub const DEV_LOG_FILE: &str = "03_Development-Log.md";
+/// Generated index note in the projects directory
+pub const DASHBOARD_FILE: &str = "_Dashboard.md";
+
+fn region_markers(name: &str) -> (String, String) {
+    (
+        format!("<!-- opj:{}:start -->", name),
+        format!("<!-- opj:{}:end -->", name),
+    )
+}
+
+/// Replace the body of a generated region delimited by
+/// `<!-- opj:<name>:start -->` / `<!-- opj:<name>:end -->` markers, leaving
+/// everything outside the markers untouched. The region is appended when it
+/// does not exist yet, and removed entirely when `body` is `None`.
+pub fn replace_managed_region(content: &str, name: &str, body: Option<&str>) -> String {
+    let (start_marker, end_marker) = region_markers(name);
+
+    let existing = content.find(&start_marker).and_then(|start| {
+        content[start..].find(&end_marker)
+            .map(|end| (start, start + end + end_marker.len()))
+    });
+
+    let region = body.map(|body| {
+        format!("{}\n{}\n{}", start_marker, body.trim_end_matches('\n'), end_marker)
+    });
+
+    match (existing, region) {
+        (Some((start, end)), Some(region)) => {
+            format!("{}{}{}", &content[..start], region, &content[end..])
+        }
+        (Some((start, end)), None) => {
+            let before = content[..start].trim_end_matches('\n');
+            let after = content[end..].trim_start_matches('\n');
+            match (before.is_empty(), after.is_empty()) {
+                (true, _) => after.to_string(),
+                (false, true) => format!("{}\n", before),
+                (false, false) => format!("{}\n\n{}", before, after),
+            }
+        }
+        (None, Some(region)) => {
+            let before = content.trim_end_matches('\n');
+            if before.is_empty() {
+                format!("{}\n", region)
+            } else {
+                format!("{}\n\n{}\n", before, region)
+            }
+        }
+        (None, None) => content.to_string(),
+    }
+}
+
+/// Append a note to a development log, grouped under a `## <date>` heading.
+/// A new heading is started unless the log already ends with today's.
+pub fn append_log_entry(content: &str, date: &str, time: &str, note: &str) -> String {
+    let heading = format!("## {}", date);
+    let last_heading = content.lines()
+        .rfind(|line| line.starts_with("## "));
+
+    let mut updated = content.trim_end_matches('\n').to_string();
+    if last_heading != Some(heading.as_str()) {
+        if !updated.is_empty() {
+            updated.push_str("\n\n");
+        }
+        updated.push_str(&heading);
+        updated.push('\n');
+    }
+    updated.push_str(&format!("\n- **{}** {}\n", time, note));
+
+    updated
+}
+
+/// Set a top-level `key: value` line in a note's YAML frontmatter, adding the
+/// key (or the frontmatter block) when missing. The rest of the note is kept
+/// byte for byte, and new lines use the note's own line ending.
+pub fn set_frontmatter_value(content: &str, key: &str, value: &str) -> String {
+    let newline = line_ending(content);
+    let line = format!("{}: {}", key, value);
+    let new_block = || format!("---{nl}{}{nl}---{nl}{}", line, content, nl = newline);
+    let Some(rest) = content.strip_prefix("---").and_then(|rest| rest.strip_prefix(newline)) else {
+        return new_block();
+    };
+    let closing = format!("{}---", newline);
+    let Some(end) = rest.find(&closing).map(|i| i + newline.len()).or_else(|| rest.starts_with("---").then_some(0)) else {
+        return new_block();
+    };
+
+    let prefix = format!("{}:", key);
+    let mut found = false;
+    let mut lines: Vec<String> = rest[..end].lines()
+        .map(|existing| {
+            if existing.starts_with(&prefix) {
+                found = true;
+                line.clone()
+            } else {
+                existing.to_string()
+            }
+        })
+        .collect();
+    if !found {
+        lines.push(line);
+    }
+
+    format!("---{nl}{}{nl}{}", lines.join(newline), &rest[end..], nl = newline)
+}
+
+/// `\r\n` for notes written with Windows line endings, `\n` otherwise.
+pub fn line_ending(content: &str) -> &'static str {
+    match content.find('\n') {
+        Some(i) if content[..i].ends_with('\r') => "\r\n",
+        _ => "\n",
+    }
+}
+
+pub fn wikilink(target: &str, alias: &str) -> String {
+    if alias.is_empty() || target == alias {
+        format!("[[{}]]", target)
+    } else {
+        format!("[[{}|{}]]", target, alias)
+    }
+}
+
+/// Wikilink to a project's overview note, relative to the vault root so that
+/// it is unambiguous across projects.
+pub fn overview_wikilink(project: &Project, vault_path: &Path) -> String {
+    let target = project.path.as_ref()
+        .map(|path| format!("{}/{}", vault_relative(path, vault_path), OVERVIEW_FILE.trim_end_matches(".md")))
+        .unwrap_or_else(|| project.name.clone());
+
+    wikilink(&target, &project.name)
+}
+
+/// A path as Obsidian writes it in links: relative to the vault, with `/`.
+pub fn vault_relative(path: &Path, vault_path: &Path) -> String {
+    path.strip_prefix(vault_path).unwrap_or(path).to_string_lossy().replace('\\', "/")
+}
+
+/// Point wikilinks and embeds into the vault folder `old` at `new` instead,
+/// e.g. after the folder was moved. Links by note name alone resolve wherever
+/// the note is and are left as they are.
+pub fn rewrite_wikilinks(content: &str, old: &str, new: &str) -> String {
+    ["/", "|", "#", "]]"].iter().fold(content.to_string(), |content, end| {
+        content.replace(&format!("[[{}{}", old, end), &format!("[[{}{}", new, end))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_wikilinks() {
+        let note = "See [[Projects/App/00_PROJECT-OVERVIEW|App]], ![[Projects/App/diagram.png]] \
+            and [[Projects/App#Goals]], [[Projects/App]] but not [[Projects/App-2/notes]] or [[App]].";
+
+        assert_eq!(
+            rewrite_wikilinks(note, "Projects/App", "Projects/_ARCHIVE/2026/App"),
+            "See [[Projects/_ARCHIVE/2026/App/00_PROJECT-OVERVIEW|App]], ![[Projects/_ARCHIVE/2026/App/diagram.png]] \
+            and [[Projects/_ARCHIVE/2026/App#Goals]], [[Projects/_ARCHIVE/2026/App]] but not [[Projects/App-2/notes]] or [[App]]."
+        );
+    }
+
+    #[test]
+    fn test_replace_managed_region_appends_and_replaces() {
+        let content = "# Title\n\nBody text\n";
+        let added = replace_managed_region(content, "relations", Some("- a"));
+        assert_eq!(
+            added,
+            "# Title\n\nBody text\n\n<!-- opj:relations:start -->\n- a\n<!-- opj:relations:end -->\n"
+        );
+
+        let edited = added.replace("Body text", "Edited body");
+        let replaced = replace_managed_region(&edited, "relations", Some("- b\n"));
+        assert!(replaced.contains("Edited body"));
+        assert!(replaced.contains("<!-- opj:relations:start -->\n- b\n<!-- opj:relations:end -->"));
+        assert!(!replaced.contains("- a"));
+    }
+
+    #[test]
+    fn test_replace_managed_region_removes() {
+        let content = "# Title\n\n<!-- opj:x:start -->\nold\n<!-- opj:x:end -->\n\n## Footer\n";
+        assert_eq!(replace_managed_region(content, "x", None), "# Title\n\n## Footer\n");
+        assert_eq!(replace_managed_region("# Title\n", "x", None), "# Title\n");
+    }
+
+    #[test]
+    fn test_append_log_entry() {
+        let log = append_log_entry("# Development Log\n", "2025-06-18", "09:00", "Started");
+        assert_eq!(log, "# Development Log\n\n## 2025-06-18\n\n- **09:00** Started\n");
+
+        let log = append_log_entry(&log, "2025-06-18", "10:30", "Parser done");
+        assert!(log.ends_with("- **09:00** Started\n- **10:30** Parser done\n"));
+        assert_eq!(log.matches("## 2025-06-18").count(), 1);
+
+        let log = append_log_entry(&log, "2025-06-19", "08:00", "Tests");
+        assert!(log.ends_with("## 2025-06-19\n\n- **08:00** Tests\n"));
+    }
+
+    #[test]
+    fn test_set_frontmatter_value() {
+        let note = "---\ntitle: A\nstatus: idea\n---\n# A\n";
+        assert_eq!(set_frontmatter_value(note, "status", "development"), "---\ntitle: A\nstatus: development\n---\n# A\n");
+        assert_eq!(set_frontmatter_value(note, "priority", "high"), "---\ntitle: A\nstatus: idea\npriority: high\n---\n# A\n");
+        assert_eq!(set_frontmatter_value("# A\n", "status", "idea"), "---\nstatus: idea\n---\n# A\n");
+
+        let crlf = "---\r\nstatus: idea\r\n---\r\n# A\r\n";
+        assert_eq!(set_frontmatter_value(crlf, "status", "testing"), "---\r\nstatus: testing\r\n---\r\n# A\r\n");
+        assert_eq!(set_frontmatter_value(crlf, "due", "2026-01-01"), "---\r\nstatus: idea\r\ndue: 2026-01-01\r\n---\r\n# A\r\n");
+        assert_eq!(set_frontmatter_value("# A\r\n", "status", "idea"), "---\r\nstatus: idea\r\n---\r\n# A\r\n");
+    }
+
+    #[test]
+    fn test_wikilink() {
+        assert_eq!(wikilink("Projects/A/00_PROJECT-OVERVIEW", "A"), "[[Projects/A/00_PROJECT-OVERVIEW|A]]");
+        assert_eq!(wikilink("Note", "Note"), "[[Note]]");
+    }
+}
@@ -1,6 +1,9 @@
 pub mod template;
 pub mod date;
 pub mod output;
+pub mod markdown;
+pub mod export;
+pub mod error;
 
 pub use template::*;
 pub use date::*;
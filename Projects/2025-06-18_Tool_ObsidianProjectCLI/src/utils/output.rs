@@ -1,4 +1,112 @@
 use colored::*;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use crate::fs::scanner::ScanWarning;
+use crate::utils::error::{self, error_code};
+
+/// Version of the `--json` envelope and payload schemas
+/// (see docs/json-output.md). Bumped on incompatible changes.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+static COMMAND_NAME: OnceLock<String> = OnceLock::new();
+
+pub fn set_json_output(enabled: bool) {
+    JSON_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether commands should emit a JSON envelope instead of text.
+pub fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Record the command name reported in envelopes, e.g. `tasks list`.
+pub fn set_command_name(name: String) {
+    let _ = COMMAND_NAME.set(name);
+}
+
+pub fn command_name() -> &'static str {
+    COMMAND_NAME.get().map(String::as_str).unwrap_or_default()
+}
+
+pub fn success_envelope(command: &str, data: Value) -> Value {
+    json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "ok": true,
+        "command": command,
+        "data": data,
+    })
+}
+
+/// `command` is `None` when the arguments could not be parsed.
+pub fn error_envelope(command: Option<&str>, error: &anyhow::Error) -> Value {
+    json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "ok": false,
+        "command": command,
+        "error": {
+            "code": error_code(error),
+            "message": format!("{:#}", error),
+        },
+    })
+}
+
+/// Print the success envelope for the running command.
+pub fn emit_json(data: Value) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(&success_envelope(command_name(), data))?);
+    Ok(())
+}
+
+/// Like [`emit_json`], adding an envelope-level `warnings` array for
+/// project directories the scan had to skip.
+pub fn emit_json_with_warnings(data: Value, warnings: &[ScanWarning]) -> anyhow::Result<()> {
+    let mut envelope = success_envelope(command_name(), data);
+    if !warnings.is_empty() {
+        envelope["warnings"] = warnings.iter()
+            .map(|warning| json!({
+                "path": warning.path.display().to_string(),
+                "message": warning.message,
+                "line": warning.line,
+                "column": warning.column,
+            }))
+            .collect();
+    }
+    println!("{}", serde_json::to_string_pretty(&envelope)?);
+    Ok(())
+}
+
+/// Footer listing skipped project directories. Goes to stderr so that
+/// exported data on stdout stays clean.
+pub fn print_scan_warnings(warnings: &[ScanWarning]) {
+    if warnings.is_empty() {
+        return;
+    }
+
+    eprintln!();
+    eprintln!("{} {} project director{} could not be loaded:",
+        "⚠️".bright_yellow(),
+        warnings.len(),
+        if warnings.len() == 1 { "y" } else { "ies" }
+    );
+    for warning in warnings {
+        eprintln!("  {} {}", format!("{}:", warning.path.display()).bright_white(), warning.message.bright_yellow());
+    }
+    eprintln!("{}", "💡 Run 'opj doctor' for details, or pass --strict to fail instead".bright_black());
+}
+
+/// Fail with an `unsupported` error in JSON mode, for interactive or
+/// not-yet-implemented commands that have no machine-readable output.
+pub fn require_text_output(what: &str) -> anyhow::Result<()> {
+    if json_output() {
+        return Err(error::cli_error(
+            error::ErrorCode::Unsupported,
+            format!("{} is not available with --json", what),
+        ));
+    }
+    Ok(())
+}
 
 pub fn print_success(message: &str) {
     println!("{} {}", "✅".bright_green(), message.bright_white());
@@ -102,11 +210,28 @@ pub fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// One block character per value, scaled to the largest value.
+pub fn sparkline(values: &[f64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let max = values.iter().cloned().fold(0.0, f64::max);
+    values.iter()
+        .map(|value| {
+            if max <= 0.0 {
+                BLOCKS[0]
+            } else {
+                BLOCKS[((value / max) * (BLOCKS.len() - 1) as f64).round() as usize]
+            }
+        })
+        .collect()
+}
+
 pub fn truncate_string(s: &str, max_length: usize) -> String {
-    if s.len() <= max_length {
+    if s.chars().count() <= max_length {
         s.to_string()
     } else {
-        format!("{}...", &s[..max_length.saturating_sub(3)])
+        let truncated: String = s.chars().take(max_length.saturating_sub(3)).collect();
+        format!("{}...", truncated)
     }
 }
 
@@ -130,6 +255,30 @@ mod tests {
         assert_eq!(truncate_string("hello world", 8), "hello...");
         assert_eq!(truncate_string("hi", 5), "hi");
         assert_eq!(truncate_string("", 5), "");
+        assert_eq!(truncate_string("プロジェクト管理ツール", 8), "プロジェク...");
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0.0, 1.0, 2.0, 4.0]), "▁▃▅█");
+        assert_eq!(sparkline(&[0.0, 0.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_json_envelopes() {
+        let success = success_envelope("info", json!({"name": "Api"}));
+        assert_eq!(success, json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "ok": true,
+            "command": "info",
+            "data": {"name": "Api"},
+        }));
+
+        let failure = error_envelope(Some("info"), &error::not_found("Project 'x' not found"));
+        assert_eq!(failure["ok"], json!(false));
+        assert_eq!(failure["error"], json!({"code": "not_found", "message": "Project 'x' not found"}));
+        assert_eq!(error_envelope(None, &anyhow::anyhow!("bad"))["command"], Value::Null);
     }
 
     #[test]